@@ -0,0 +1,90 @@
+#[cfg(feature = "bytemuck")]
+use bytemuck::Zeroable;
+
+use crate::{ColorInterop, ColorType, Spaces};
+
+/// A color padded with one extra component and aligned to 16 bytes.
+///
+/// This matches the layout of a `vec3` in GPU uniform and storage buffers and is convenient
+/// for aligned SIMD loads. For 3-component colors with 32-bit components, i.e. [`LinearSrgb<f32>`][crate::LinearSrgb],
+/// the result is exactly 16 bytes with no implicit padding, so with the `bytemuck` feature it
+/// implements `Pod`.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, PartialOrd, Eq, Ord)]
+#[repr(C, align(16))]
+pub struct Padded16<ColorTy: ColorType> {
+    /// The contained color.
+    pub color: ColorTy,
+    /// Explicit padding, which has no meaning.
+    pub padding: ColorTy::ComponentTy,
+}
+
+impl<ColorTy: ColorType> Padded16<ColorTy> {
+    /// Get the contained, tightly packed color.
+    pub fn into_inner(self) -> ColorTy {
+        self.color
+    }
+}
+
+impl<ColorTy: ColorType> From<ColorTy> for Padded16<ColorTy>
+where
+    ColorTy::ComponentTy: Default,
+{
+    fn from(color: ColorTy) -> Self {
+        Self {
+            color,
+            padding: Default::default(),
+        }
+    }
+}
+
+impl<BaseColorTy: ColorType> ColorType for Padded16<BaseColorTy> {
+    type ComponentTy = BaseColorTy::ComponentTy;
+    const SPACE: Spaces = BaseColorTy::SPACE;
+    const NUM_COMPONENTS: usize = BaseColorTy::NUM_COMPONENTS;
+}
+
+impl<ColorTy: ColorType> ColorInterop for Padded16<ColorTy> {
+    type CintTy = Self;
+}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<ColorTy: ColorType + Zeroable> Zeroable for Padded16<ColorTy> {}
+
+/// A color aligned to 16 bytes.
+///
+/// This is meant for colors which are already 16 bytes in size, i.e. [`Alpha<LinearSrgb<f32>>`][crate::Alpha],
+/// and so matches the layout of a `vec4` in GPU uniform and storage buffers. For 3-component colors
+/// with an alpha component and 32-bit components, there is no implicit padding, so with the `bytemuck`
+/// feature it implements `Pod`.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, PartialOrd, Eq, Ord)]
+#[repr(C, align(16))]
+pub struct Aligned16<ColorTy: ColorType> {
+    /// The contained color.
+    pub color: ColorTy,
+}
+
+impl<ColorTy: ColorType> Aligned16<ColorTy> {
+    /// Get the contained, tightly packed color.
+    pub fn into_inner(self) -> ColorTy {
+        self.color
+    }
+}
+
+impl<ColorTy: ColorType> From<ColorTy> for Aligned16<ColorTy> {
+    fn from(color: ColorTy) -> Self {
+        Self { color }
+    }
+}
+
+impl<BaseColorTy: ColorType> ColorType for Aligned16<BaseColorTy> {
+    type ComponentTy = BaseColorTy::ComponentTy;
+    const SPACE: Spaces = BaseColorTy::SPACE;
+    const NUM_COMPONENTS: usize = BaseColorTy::NUM_COMPONENTS;
+}
+
+impl<ColorTy: ColorType> ColorInterop for Aligned16<ColorTy> {
+    type CintTy = Self;
+}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<ColorTy: ColorType + Zeroable> Zeroable for Aligned16<ColorTy> {}
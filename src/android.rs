@@ -0,0 +1,354 @@
+//! Mapping between Android's `ADataSpace` and `ColorSpace.Named` constants and `cint` color
+//! descriptors.
+//!
+//! Android describes the colors of a buffer with an `ADataSpace` (`android.hardware.DataSpace` in
+//! Java), a bit field made of a `STANDARD_*` (primaries and Y'CbCr matrix), a `TRANSFER_*`, and a
+//! `RANGE_*` component. [`AndroidColorInfo`] holds these components so that
+//! [`AndroidColorInfo::descriptor`] can translate them into a [`ColorDescriptor`], and
+//! [`AndroidColorInfo::from_descriptor`] can translate them back. The color spaces of
+//! `android.graphics.ColorSpace.Named` are mapped by [`NamedColorSpace`].
+
+use crate::{
+    ColorDescriptor, Error, MatrixCoefficients, PixelFormat, SignalRange, SpaceRef, Spaces,
+    TransferFunction,
+};
+
+/// The `STANDARD_*` components of an `ADataSpace`, already shifted into place.
+pub mod standard {
+    pub const MASK: i32 = 63 << 16;
+    pub const UNSPECIFIED: i32 = 0;
+    pub const BT709: i32 = 1 << 16;
+    pub const BT601_625: i32 = 2 << 16;
+    pub const BT601_625_UNADJUSTED: i32 = 3 << 16;
+    pub const BT601_525: i32 = 4 << 16;
+    pub const BT601_525_UNADJUSTED: i32 = 5 << 16;
+    pub const BT2020: i32 = 6 << 16;
+    pub const BT2020_CONSTANT_LUMINANCE: i32 = 7 << 16;
+    pub const BT470M: i32 = 8 << 16;
+    pub const FILM: i32 = 9 << 16;
+    pub const DCI_P3: i32 = 10 << 16;
+    pub const ADOBE_RGB: i32 = 11 << 16;
+}
+
+/// The `TRANSFER_*` components of an `ADataSpace`, already shifted into place.
+pub mod transfer {
+    pub const MASK: i32 = 31 << 22;
+    pub const UNSPECIFIED: i32 = 0;
+    pub const LINEAR: i32 = 1 << 22;
+    pub const SRGB: i32 = 2 << 22;
+    pub const SMPTE_170M: i32 = 3 << 22;
+    pub const GAMMA2_2: i32 = 4 << 22;
+    pub const GAMMA2_6: i32 = 5 << 22;
+    pub const GAMMA2_8: i32 = 6 << 22;
+    pub const ST2084: i32 = 7 << 22;
+    pub const HLG: i32 = 8 << 22;
+}
+
+/// The `RANGE_*` components of an `ADataSpace`, already shifted into place.
+pub mod range {
+    pub const MASK: i32 = 7 << 27;
+    pub const UNSPECIFIED: i32 = 0;
+    pub const FULL: i32 = 1 << 27;
+    pub const LIMITED: i32 = 2 << 27;
+    pub const EXTENDED: i32 = 3 << 27;
+}
+
+/// The named `ADATASPACE_*` values.
+pub mod data_space {
+    use super::{range, standard, transfer};
+
+    pub const UNKNOWN: i32 = 0;
+    pub const SCRGB_LINEAR: i32 = standard::BT709 | transfer::LINEAR | range::EXTENDED;
+    pub const SRGB: i32 = standard::BT709 | transfer::SRGB | range::FULL;
+    pub const SCRGB: i32 = standard::BT709 | transfer::SRGB | range::EXTENDED;
+    pub const DISPLAY_P3: i32 = standard::DCI_P3 | transfer::SRGB | range::FULL;
+    pub const BT2020_PQ: i32 = standard::BT2020 | transfer::ST2084 | range::FULL;
+    pub const BT2020_ITU_PQ: i32 = standard::BT2020 | transfer::ST2084 | range::LIMITED;
+    pub const ADOBE_RGB: i32 = standard::ADOBE_RGB | transfer::GAMMA2_2 | range::FULL;
+    pub const JFIF: i32 = standard::BT601_625 | transfer::SMPTE_170M | range::FULL;
+    pub const BT601_625: i32 = standard::BT601_625 | transfer::SMPTE_170M | range::LIMITED;
+    pub const BT601_525: i32 = standard::BT601_525 | transfer::SMPTE_170M | range::LIMITED;
+    pub const BT2020: i32 = standard::BT2020 | transfer::SMPTE_170M | range::FULL;
+    pub const BT709: i32 = standard::BT709 | transfer::SMPTE_170M | range::LIMITED;
+    pub const DCI_P3: i32 = standard::DCI_P3 | transfer::GAMMA2_6 | range::FULL;
+    pub const SRGB_LINEAR: i32 = standard::BT709 | transfer::LINEAR | range::FULL;
+    pub const BT2020_HLG: i32 = standard::BT2020 | transfer::HLG | range::FULL;
+    pub const BT2020_ITU_HLG: i32 = standard::BT2020 | transfer::HLG | range::LIMITED;
+    pub const DISPLAY_P3_LINEAR: i32 = standard::DCI_P3 | transfer::LINEAR | range::FULL;
+}
+
+/// The spaces described by pairs of `STANDARD_*` and `TRANSFER_*` components, in order of
+/// preference for mapping a space back to its components.
+///
+/// `STANDARD_DCI_P3` has the D65 white point of Display P3, so `DATASPACE_DCI_P3`, with its 2.6
+/// gamma, has no `cint` space: [`Spaces::EncodedDciP3`] uses the D60 white point.
+const SPACES: &[(i32, i32, Spaces)] = {
+    use standard::*;
+    use transfer::{GAMMA2_2, HLG, LINEAR, SMPTE_170M, SRGB, ST2084};
+
+    &[
+        (BT709, SRGB, Spaces::EncodedSrgb),
+        (BT709, LINEAR, Spaces::LinearSrgb),
+        (BT709, SMPTE_170M, Spaces::EncodedRec709),
+        (BT601_625, SMPTE_170M, Spaces::EncodedBt601_625),
+        (BT601_625_UNADJUSTED, SMPTE_170M, Spaces::EncodedBt601_625),
+        (BT601_625, LINEAR, Spaces::Bt601_625),
+        (BT601_525, SMPTE_170M, Spaces::EncodedBt601_525),
+        (BT601_525_UNADJUSTED, SMPTE_170M, Spaces::EncodedBt601_525),
+        (BT601_525, LINEAR, Spaces::Bt601_525),
+        (BT2020, SMPTE_170M, Spaces::EncodedBt2020),
+        (BT2020, LINEAR, Spaces::Bt2020),
+        (BT2020, ST2084, Spaces::EncodedBt2100PQ),
+        (BT2020, HLG, Spaces::EncodedBt2100HLG),
+        (DCI_P3, SRGB, Spaces::EncodedDisplayP3),
+        (DCI_P3, LINEAR, Spaces::DisplayP3),
+        (DCI_P3, ST2084, Spaces::EncodedDisplayP3PQ),
+        (ADOBE_RGB, GAMMA2_2, Spaces::EncodedAdobeRgb1998),
+        (ADOBE_RGB, LINEAR, Spaces::AdobeRgb1998),
+    ]
+};
+
+/// The components of an Android `ADataSpace`.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct AndroidColorInfo {
+    /// One of the [`standard`] components.
+    pub standard: i32,
+    /// One of the [`transfer`] components.
+    pub transfer: i32,
+    /// One of the [`range`] components.
+    pub range: i32,
+}
+
+impl AndroidColorInfo {
+    /// Split an `ADataSpace` value, i.e. [`data_space::DISPLAY_P3`], into its components.
+    pub fn from_data_space(data_space: i32) -> Self {
+        Self {
+            standard: data_space & standard::MASK,
+            transfer: data_space & transfer::MASK,
+            range: data_space & range::MASK,
+        }
+    }
+
+    /// Combine the components into an `ADataSpace` value.
+    pub fn data_space(&self) -> i32 {
+        self.standard | self.transfer | self.range
+    }
+
+    /// The transfer function given by the transfer component, or `None` if it is unspecified or
+    /// not known to `cint`.
+    pub fn transfer_function(&self) -> Option<TransferFunction> {
+        match self.transfer {
+            transfer::LINEAR => Some(TransferFunction::Linear),
+            transfer::SRGB => Some(TransferFunction::Srgb),
+            transfer::SMPTE_170M => Some(TransferFunction::Bt709),
+            transfer::GAMMA2_2 => Some(TransferFunction::Gamma22),
+            transfer::GAMMA2_6 => Some(TransferFunction::Dci),
+            transfer::ST2084 => Some(TransferFunction::Pq),
+            transfer::HLG => Some(TransferFunction::Hlg),
+            _ => None,
+        }
+    }
+
+    /// The Y'CbCr matrix given by the standard component, for use with Y'CbCr buffers, or `None`
+    /// if it is unspecified or not known to `cint`.
+    pub fn matrix_coefficients(&self) -> Option<MatrixCoefficients> {
+        match self.standard {
+            standard::BT709 => Some(MatrixCoefficients::Bt709),
+            standard::BT601_625
+            | standard::BT601_625_UNADJUSTED
+            | standard::BT601_525
+            | standard::BT601_525_UNADJUSTED => Some(MatrixCoefficients::Bt601),
+            standard::BT2020 => Some(MatrixCoefficients::Bt2020Ncl),
+            standard::BT2020_CONSTANT_LUMINANCE => Some(MatrixCoefficients::Bt2020Cl),
+            _ => None,
+        }
+    }
+
+    /// Translate the components into a [`ColorDescriptor`] for RGB colors.
+    ///
+    /// `RANGE_EXTENDED` is described as full range, and selects [`Spaces::EncodedExtendedSrgb`]
+    /// and [`Spaces::ScRgb`] for the sRGB standard. An unspecified range is treated as full range.
+    ///
+    /// Fails with [`Error::UnsupportedFormat`] if the standard and transfer components don't
+    /// describe a space known to `cint`.
+    pub fn descriptor(&self) -> Result<ColorDescriptor, Error> {
+        let (_, _, mut space) = *SPACES
+            .iter()
+            .find(|(standard, transfer, _)| {
+                *standard == self.standard && *transfer == self.transfer
+            })
+            .ok_or(Error::UnsupportedFormat)?;
+        if self.range == range::EXTENDED {
+            space = match space {
+                Spaces::EncodedSrgb => Spaces::EncodedExtendedSrgb,
+                Spaces::LinearSrgb => Spaces::ScRgb,
+                space => space,
+            };
+        }
+        let mut descriptor = ColorDescriptor::new(space);
+        if self.range == range::LIMITED {
+            descriptor.signal_range = SignalRange::Limited;
+        }
+        Ok(descriptor)
+    }
+
+    /// Translate a [`ColorDescriptor`] into the components Android uses for it, the inverse of
+    /// [`AndroidColorInfo::descriptor`].
+    ///
+    /// Fails with [`Error::UnsupportedFormat`] if the space has no `ADataSpace` equivalent.
+    pub fn from_descriptor(descriptor: &ColorDescriptor) -> Result<Self, Error> {
+        let space = match descriptor.space {
+            SpaceRef::Builtin(space) => space,
+            SpaceRef::Custom(_) => return Err(Error::UnsupportedFormat),
+        };
+        let (space, extended) = match space {
+            Spaces::EncodedExtendedSrgb => (Spaces::EncodedSrgb, true),
+            Spaces::ScRgb => (Spaces::LinearSrgb, true),
+            space => (space, false),
+        };
+        let (standard, transfer, _) = *SPACES
+            .iter()
+            .find(|(_, _, known)| *known == space)
+            .ok_or(Error::UnsupportedFormat)?;
+        let range = match descriptor.signal_range {
+            _ if extended => range::EXTENDED,
+            SignalRange::Full => range::FULL,
+            SignalRange::Limited => range::LIMITED,
+        };
+        Ok(Self {
+            standard,
+            transfer,
+            range,
+        })
+    }
+
+    /// Translate a [`PixelFormat`] into the components Android uses for it.
+    ///
+    /// RGB formats are translated as [`AndroidColorInfo::from_descriptor`] does. Y'CbCr formats
+    /// are translated by their matrix, with the BT.709 transfer function that Android uses for
+    /// video, i.e. BT.709 limited range Y'CbCr becomes [`data_space::BT709`].
+    ///
+    /// Fails with [`Error::UnsupportedFormat`] if the space or Y'CbCr matrix has no `ADataSpace`
+    /// equivalent.
+    pub fn from_pixel_format(format: &PixelFormat) -> Result<Self, Error> {
+        if format.descriptor.space != SpaceRef::Builtin(Spaces::YPrimeCbCr) {
+            return Self::from_descriptor(&format.descriptor);
+        }
+        let standard = match format.matrix_coefficients {
+            Some(MatrixCoefficients::Bt709) => standard::BT709,
+            Some(MatrixCoefficients::Bt601) => standard::BT601_625,
+            Some(MatrixCoefficients::Bt2020Ncl) => standard::BT2020,
+            Some(MatrixCoefficients::Bt2020Cl) => standard::BT2020_CONSTANT_LUMINANCE,
+            _ => return Err(Error::UnsupportedFormat),
+        };
+        let range = match format.descriptor.signal_range {
+            SignalRange::Full => range::FULL,
+            SignalRange::Limited => range::LIMITED,
+        };
+        Ok(Self {
+            standard,
+            transfer: transfer::SMPTE_170M,
+            range,
+        })
+    }
+}
+
+/// The color spaces of Android's `android.graphics.ColorSpace.Named`.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum NamedColorSpace {
+    Srgb,
+    LinearSrgb,
+    ExtendedSrgb,
+    LinearExtendedSrgb,
+    Bt709,
+    Bt2020,
+    DciP3,
+    DisplayP3,
+    Ntsc1953,
+    SmpteC,
+    AdobeRgb,
+    ProPhotoRgb,
+    Aces,
+    AcesCg,
+    CieXyz,
+    CieLab,
+    Bt2020Hlg,
+    Bt2020Pq,
+    OkLab,
+}
+
+/// The Java names of the named color spaces, with the `cint` space each one maps to.
+const NAMED_COLOR_SPACES: &[(NamedColorSpace, &str, Option<Spaces>)] = {
+    use NamedColorSpace::*;
+
+    &[
+        (Srgb, "SRGB", Some(Spaces::EncodedSrgb)),
+        (LinearSrgb, "LINEAR_SRGB", Some(Spaces::LinearSrgb)),
+        (
+            ExtendedSrgb,
+            "EXTENDED_SRGB",
+            Some(Spaces::EncodedExtendedSrgb),
+        ),
+        (
+            LinearExtendedSrgb,
+            "LINEAR_EXTENDED_SRGB",
+            Some(Spaces::ScRgb),
+        ),
+        (Bt709, "BT709", Some(Spaces::EncodedRec709)),
+        (Bt2020, "BT2020", Some(Spaces::EncodedBt2020)),
+        // Uses the DCI white point, while `cint`'s DCI-P3 spaces use the D60 white point of ACES
+        (DciP3, "DCI_P3", None),
+        (DisplayP3, "DISPLAY_P3", Some(Spaces::EncodedDisplayP3)),
+        (Ntsc1953, "NTSC_1953", None),
+        (SmpteC, "SMPTE_C", Some(Spaces::EncodedBt601_525)),
+        (AdobeRgb, "ADOBE_RGB", Some(Spaces::EncodedAdobeRgb1998)),
+        (
+            ProPhotoRgb,
+            "PRO_PHOTO_RGB",
+            Some(Spaces::EncodedProPhotoRgb),
+        ),
+        (Aces, "ACES", Some(Spaces::Aces2065)),
+        (AcesCg, "ACESCG", Some(Spaces::AcesCg)),
+        (CieXyz, "CIE_XYZ", Some(Spaces::CieXYZ)),
+        (CieLab, "CIE_LAB", Some(Spaces::CieLab)),
+        (Bt2020Hlg, "BT2020_HLG", Some(Spaces::EncodedBt2100HLG)),
+        (Bt2020Pq, "BT2020_PQ", Some(Spaces::EncodedBt2100PQ)),
+        (OkLab, "OK_LAB", Some(Spaces::Oklab)),
+    ]
+};
+
+impl NamedColorSpace {
+    /// The name of the `ColorSpace.Named` constant, i.e. `"DISPLAY_P3"`.
+    pub fn name(self) -> &'static str {
+        NAMED_COLOR_SPACES
+            .iter()
+            .find(|(named, _, _)| *named == self)
+            .map(|(_, name, _)| *name)
+            .unwrap_or_default()
+    }
+
+    /// The named color space with the given `ColorSpace.Named` constant name, if known.
+    pub fn from_name(name: &str) -> Option<Self> {
+        NAMED_COLOR_SPACES
+            .iter()
+            .find(|(_, known, _)| *known == name)
+            .map(|(named, _, _)| *named)
+    }
+
+    /// The `cint` space of this named color space, or `None` if `cint` has no equivalent (i.e. for
+    /// [`NamedColorSpace::Ntsc1953`]).
+    pub fn space(self) -> Option<Spaces> {
+        NAMED_COLOR_SPACES
+            .iter()
+            .find(|(named, _, _)| *named == self)
+            .and_then(|(_, _, space)| *space)
+    }
+
+    /// The named color space for a `cint` space, if Android has one.
+    pub fn from_space(space: Spaces) -> Option<Self> {
+        NAMED_COLOR_SPACES
+            .iter()
+            .find(|(_, _, known)| *known == Some(space))
+            .map(|(named, _, _)| *named)
+    }
+}
@@ -0,0 +1,44 @@
+use core::ops::{Add, Mul, Sub};
+
+use crate::{GenericColor1, GenericColor3};
+
+macro_rules! impl_arithmetic {
+    ($($name:ident { $($compname:ident),+ })+) => {
+        $(
+            impl<ComponentTy: Add<Output = ComponentTy>> Add for $name<ComponentTy> {
+                type Output = $name<ComponentTy>;
+
+                fn add(self, rhs: $name<ComponentTy>) -> $name<ComponentTy> {
+                    $name {
+                        $($compname: self.$compname + rhs.$compname,)+
+                    }
+                }
+            }
+
+            impl<ComponentTy: Sub<Output = ComponentTy>> Sub for $name<ComponentTy> {
+                type Output = $name<ComponentTy>;
+
+                fn sub(self, rhs: $name<ComponentTy>) -> $name<ComponentTy> {
+                    $name {
+                        $($compname: self.$compname - rhs.$compname,)+
+                    }
+                }
+            }
+
+            impl<ComponentTy: Mul<Output = ComponentTy> + Copy> Mul<ComponentTy> for $name<ComponentTy> {
+                type Output = $name<ComponentTy>;
+
+                fn mul(self, rhs: ComponentTy) -> $name<ComponentTy> {
+                    $name {
+                        $($compname: self.$compname * rhs,)+
+                    }
+                }
+            }
+        )+
+    };
+}
+
+impl_arithmetic! {
+    GenericColor1 { x }
+    GenericColor3 { x, y, z }
+}
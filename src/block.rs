@@ -0,0 +1,22 @@
+use crate::ColorType;
+
+/// A block of `N` colors of type `C` stored in structure-of-arrays form.
+///
+/// Rather than storing `N` colors one after another, a [`ColorBlock`] stores all `N` values of each
+/// component contiguously, so that i.e. `ColorBlock<LinearSrgb<f32>, 8>` is a `LinearSrgb<[f32; 8]>`
+/// whose `r` field holds the red components of all eight colors. Use [`BlockColor::into_block`] and
+/// [`BlockColor::from_block`] to transpose to and from arrays of colors.
+pub type ColorBlock<C, const N: usize> = <C as BlockColor<N>>::Block;
+
+/// A color type which can be stored in blocks of `N` colors in structure-of-arrays form.
+/// See [`ColorBlock`].
+pub trait BlockColor<const N: usize>: ColorType + Sized {
+    /// The structure-of-arrays form of `N` colors of this type.
+    type Block;
+
+    /// Transpose an array of colors into a block.
+    fn into_block(colors: [Self; N]) -> Self::Block;
+
+    /// Transpose a block into an array of colors.
+    fn from_block(block: Self::Block) -> [Self; N];
+}
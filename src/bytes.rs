@@ -0,0 +1,59 @@
+use bytemuck::{Pod, PodCastError};
+
+use crate::ColorType;
+
+/// An extension trait implemented for all [`Pod`] [`ColorType`]s which provides safe
+/// reinterpretation of colors as bytes or components, without spelling out the
+/// `bytemuck` casts and their type parameters.
+///
+/// Requires the `bytemuck` feature.
+pub trait CastBytes: ColorType + Pod
+where
+    Self::ComponentTy: Pod,
+{
+    /// View `self` as its underlying bytes.
+    fn as_bytes(&self) -> &[u8] {
+        bytemuck::bytes_of(self)
+    }
+
+    /// Read a color from its underlying bytes, which need not be aligned.
+    ///
+    /// Fails if `bytes` is not exactly the size of `Self`.
+    fn from_bytes(bytes: &[u8]) -> Result<Self, PodCastError> {
+        bytemuck::try_pod_read_unaligned(bytes)
+    }
+
+    /// View a slice of colors as a flat slice of their components.
+    ///
+    /// Any padding components, i.e. of [`Padded16`][crate::Padded16], are included.
+    fn cast_slice_to_components(colors: &[Self]) -> &[Self::ComponentTy] {
+        bytemuck::cast_slice(colors)
+    }
+
+    /// View a mutable slice of colors as a flat mutable slice of their components.
+    ///
+    /// Any padding components, i.e. of [`Padded16`][crate::Padded16], are included.
+    fn cast_slice_to_components_mut(colors: &mut [Self]) -> &mut [Self::ComponentTy] {
+        bytemuck::cast_slice_mut(colors)
+    }
+
+    /// View a flat slice of components as a slice of colors.
+    ///
+    /// Fails if the slice is not a whole number of colors long or is insufficiently aligned for `Self`.
+    fn cast_slice_from_components(
+        components: &[Self::ComponentTy],
+    ) -> Result<&[Self], PodCastError> {
+        bytemuck::try_cast_slice(components)
+    }
+
+    /// View a flat mutable slice of components as a mutable slice of colors.
+    ///
+    /// Fails if the slice is not a whole number of colors long or is insufficiently aligned for `Self`.
+    fn cast_slice_from_components_mut(
+        components: &mut [Self::ComponentTy],
+    ) -> Result<&mut [Self], PodCastError> {
+        bytemuck::try_cast_slice_mut(components)
+    }
+}
+
+impl<ColorTy: ColorType + Pod> CastBytes for ColorTy where ColorTy::ComponentTy: Pod {}
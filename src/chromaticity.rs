@@ -0,0 +1,26 @@
+#[cfg(feature = "bytemuck")]
+use bytemuck::{Pod, Zeroable};
+
+/// A CIE 1931 xy chromaticity coordinate.
+///
+/// Used to describe color primaries and white points in metadata.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Default)]
+#[repr(C)]
+pub struct Chromaticity {
+    /// The x coordinate.
+    pub x: f32,
+    /// The y coordinate.
+    pub y: f32,
+}
+
+impl Chromaticity {
+    /// Create a new [`Chromaticity`] from its `x` and `y` coordinates.
+    pub const fn new(x: f32, y: f32) -> Self {
+        Self { x, y }
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl Zeroable for Chromaticity {}
+#[cfg(feature = "bytemuck")]
+unsafe impl Pod for Chromaticity {}
@@ -0,0 +1,130 @@
+use core::ops::{Deref, DerefMut};
+
+#[cfg(feature = "bytemuck")]
+use bytemuck::{Pod, Zeroable};
+
+use crate::{ColorInterop, ColorRepr, ColorType, Spaces};
+
+/// A trait implemented by the zero-sized marker types in the [`space`][crate::space] module,
+/// relating each of them to its concrete color type.
+pub trait Space: 'static {
+    /// The variant of [`Spaces`] this marker represents.
+    const SPACE: Spaces;
+    /// The number of components of colors in this space.
+    const NUM_COMPONENTS: usize;
+    /// The concrete color type of this space with the given component type, i.e.
+    /// [`EncodedSrgb<ComponentTy>`][crate::EncodedSrgb] for [`space::EncodedSrgb`][crate::space::EncodedSrgb].
+    type Color<ComponentTy: Copy>: ColorRepr<ComponentTy = ComponentTy>;
+}
+
+/// A color in the space `S` with components of type `T`.
+///
+/// This is an alternative to the concrete color types which is generic over its space, so generic
+/// code can write a single impl covering all spaces, i.e. `impl<S: Space> MyTrait for Color<S, f32>`.
+/// It has the same layout as the concrete color type `S::Color<T>`, derefs to it to provide named
+/// access to its components, and can be converted to and from it with `From`.
+#[repr(transparent)]
+pub struct Color<S: Space, T: Copy> {
+    /// The contained concrete color.
+    pub inner: S::Color<T>,
+}
+
+impl<S: Space, T: Copy> Color<S, T> {
+    /// Create a new [`Color`] from its concrete color type.
+    pub fn new(inner: S::Color<T>) -> Self {
+        Self { inner }
+    }
+
+    /// Convert `self` into its concrete color type.
+    pub fn into_inner(self) -> S::Color<T> {
+        self.inner
+    }
+
+    /// View the components of `self` as a slice, in memory order.
+    pub fn as_slice(&self) -> &[T] {
+        // SAFETY: the `ColorRepr` contract guarantees `S::Color<T>` is laid out as `[T; NUM_COMPONENTS]`
+        unsafe {
+            core::slice::from_raw_parts(self as *const Self as *const T, Self::NUM_COMPONENTS)
+        }
+    }
+
+    /// View the components of `self` as a mutable slice, in memory order.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        // SAFETY: the `ColorRepr` contract guarantees `S::Color<T>` is laid out as `[T; NUM_COMPONENTS]`
+        unsafe {
+            core::slice::from_raw_parts_mut(self as *mut Self as *mut T, Self::NUM_COMPONENTS)
+        }
+    }
+}
+
+impl<S: Space, T: Copy> Clone for Color<S, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<S: Space, T: Copy> Copy for Color<S, T> {}
+
+impl<S: Space, T: Copy> core::fmt::Debug for Color<S, T>
+where
+    S::Color<T>: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("Color").field(&self.inner).finish()
+    }
+}
+
+impl<S: Space, T: Copy> PartialEq for Color<S, T>
+where
+    S::Color<T>: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
+}
+
+impl<S: Space, T: Copy> Eq for Color<S, T> where S::Color<T>: Eq {}
+
+impl<S: Space, T: Copy> core::hash::Hash for Color<S, T>
+where
+    S::Color<T>: core::hash::Hash,
+{
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.inner.hash(state)
+    }
+}
+
+impl<S: Space, T: Copy> Deref for Color<S, T> {
+    type Target = S::Color<T>;
+
+    fn deref(&self) -> &S::Color<T> {
+        &self.inner
+    }
+}
+
+impl<S: Space, T: Copy> DerefMut for Color<S, T> {
+    fn deref_mut(&mut self) -> &mut S::Color<T> {
+        &mut self.inner
+    }
+}
+
+impl<S: Space, T: Copy> ColorType for Color<S, T> {
+    type ComponentTy = T;
+    const SPACE: Spaces = S::SPACE;
+    const NUM_COMPONENTS: usize = <S::Color<T> as ColorType>::NUM_COMPONENTS;
+}
+
+impl<S: Space, T: Copy> ColorInterop for Color<S, T> {
+    type CintTy = Self;
+}
+
+// SAFETY: same layout is guaranteed by repr transparent
+unsafe impl<S: Space, T: Copy> ColorRepr for Color<S, T> {
+    type Repr = <S::Color<T> as ColorRepr>::Repr;
+    type AlphaRepr = <S::Color<T> as ColorRepr>::AlphaRepr;
+}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<S: Space, T: Copy> Zeroable for Color<S, T> where S::Color<T>: Zeroable {}
+#[cfg(feature = "bytemuck")]
+unsafe impl<S: Space, T: Copy + 'static> Pod for Color<S, T> where S::Color<T>: Pod {}
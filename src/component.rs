@@ -0,0 +1,197 @@
+#[cfg(feature = "bytemuck")]
+use bytemuck::{Pod, Zeroable};
+
+use crate::Error;
+
+macro_rules! bit_depth_component {
+    {
+        $(#[$doc:meta])*
+        $name:ident($storage:ty, $bits:literal)
+    } => {
+        $(#[$doc])*
+        #[repr(transparent)]
+        #[derive(Clone, Copy, Debug, Default, Hash, PartialEq, PartialOrd, Eq, Ord)]
+        pub struct $name(pub $storage);
+
+        impl $name {
+            /// The number of significant bits in the contained value.
+            pub const BITS: u32 = $bits;
+            /// The maximum valid code value.
+            pub const MAX: $name = $name((1 << $bits) - 1);
+
+            /// Create a new code value, returning `None` if `value` doesn't fit in
+            #[doc = concat!(stringify!($bits), " bits.")]
+            pub const fn new(value: $storage) -> Option<Self> {
+                if value <= Self::MAX.0 {
+                    Some(Self(value))
+                } else {
+                    None
+                }
+            }
+
+            /// Get the contained code value.
+            pub const fn get(self) -> $storage {
+                self.0
+            }
+        }
+
+        impl core::convert::TryFrom<$storage> for $name {
+            type Error = Error;
+
+            fn try_from(value: $storage) -> Result<$name, Error> {
+                $name::new(value).ok_or(Error::OutOfRange)
+            }
+        }
+
+        impl From<$name> for $storage {
+            fn from(value: $name) -> $storage {
+                value.0
+            }
+        }
+
+        #[cfg(feature = "bytemuck")]
+        unsafe impl Zeroable for $name {}
+        #[cfg(feature = "bytemuck")]
+        unsafe impl Pod for $name {}
+
+        #[cfg(feature = "num-traits")]
+        impl num_traits::Bounded for $name {
+            fn min_value() -> Self {
+                $name(0)
+            }
+
+            fn max_value() -> Self {
+                Self::MAX
+            }
+        }
+
+        #[cfg(feature = "num-traits")]
+        impl num_traits::ToPrimitive for $name {
+            fn to_i64(&self) -> Option<i64> {
+                Some(self.0.into())
+            }
+
+            fn to_u64(&self) -> Option<u64> {
+                Some(self.0.into())
+            }
+        }
+
+        #[cfg(feature = "num-traits")]
+        impl num_traits::FromPrimitive for $name {
+            fn from_i64(n: i64) -> Option<Self> {
+                <$storage as num_traits::FromPrimitive>::from_i64(n).and_then(Self::new)
+            }
+
+            fn from_u64(n: u64) -> Option<Self> {
+                <$storage as num_traits::FromPrimitive>::from_u64(n).and_then(Self::new)
+            }
+        }
+
+        #[cfg(feature = "num-traits")]
+        impl num_traits::NumCast for $name {
+            fn from<T: num_traits::ToPrimitive>(n: T) -> Option<Self> {
+                n.to_u64().and_then(<Self as num_traits::FromPrimitive>::from_u64)
+            }
+        }
+    };
+}
+
+bit_depth_component! {
+    /// A 10-bit code value stored in the low bits of a `u16`.
+    ///
+    /// Useful as a component type for colors from video and RAW formats which store
+    /// 10-bit values in 16-bit containers, i.e. `EncodedBt2100PQ<U10>`.
+    U10(u16, 10)
+}
+
+bit_depth_component! {
+    /// A 12-bit code value stored in the low bits of a `u16`.
+    ///
+    /// Useful as a component type for colors from video and RAW formats which store
+    /// 12-bit values in 16-bit containers, i.e. `EncodedDciP3<U12>`.
+    U12(u16, 12)
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// A component type with canonical constant values, implemented for the primitive types
+/// commonly used as color components as well as [`U10`] and [`U12`], and for `half::f16` with the
+/// `half` feature.
+///
+/// This trait is sealed and cannot be implemented outside of `cint`.
+pub trait ColorComponent: Copy + PartialOrd + sealed::Sealed {
+    /// The value representing no intensity, i.e. `0` or `0.0`.
+    const ZERO: Self;
+    /// The value representing full intensity, i.e. `255` for `u8` or `1.0` for `f32`.
+    /// This is the value of a fully opaque alpha component.
+    const ONE: Self;
+    /// The minimum representable value.
+    const MIN: Self;
+    /// The maximum representable value.
+    const MAX: Self;
+
+    /// Whether `self` is within the nominal range from [`ZERO`][Self::ZERO] to [`ONE`][Self::ONE], inclusive.
+    fn is_normalized(self) -> bool {
+        self >= Self::ZERO && self <= Self::ONE
+    }
+}
+
+macro_rules! impl_color_component {
+    ($($ty:ty => $zero:expr, $one:expr, $min:expr, $max:expr;)+) => {
+        $(
+            impl sealed::Sealed for $ty {}
+
+            impl ColorComponent for $ty {
+                const ZERO: Self = $zero;
+                const ONE: Self = $one;
+                const MIN: Self = $min;
+                const MAX: Self = $max;
+            }
+        )+
+    };
+}
+
+impl_color_component! {
+    u8 => 0, u8::MAX, u8::MIN, u8::MAX;
+    u16 => 0, u16::MAX, u16::MIN, u16::MAX;
+    u32 => 0, u32::MAX, u32::MIN, u32::MAX;
+    f32 => 0.0, 1.0, f32::MIN, f32::MAX;
+    f64 => 0.0, 1.0, f64::MIN, f64::MAX;
+    U10 => U10(0), U10::MAX, U10(0), U10::MAX;
+    U12 => U12(0), U12::MAX, U12(0), U12::MAX;
+}
+
+#[cfg(feature = "half")]
+impl_color_component! {
+    half::f16 => half::f16::ZERO, half::f16::ONE, half::f16::MIN, half::f16::MAX;
+}
+
+/// A floating point component type, implemented for `f32` and `f64`, and for `half::f16` with the
+/// `half` feature.
+///
+/// This trait is sealed and cannot be implemented outside of `cint`.
+pub trait FloatComponent: ColorComponent {
+    /// Whether `self` is neither infinite nor NaN.
+    fn is_finite(self) -> bool;
+}
+
+impl FloatComponent for f32 {
+    fn is_finite(self) -> bool {
+        f32::is_finite(self)
+    }
+}
+
+impl FloatComponent for f64 {
+    fn is_finite(self) -> bool {
+        f64::is_finite(self)
+    }
+}
+
+#[cfg(feature = "half")]
+impl FloatComponent for half::f16 {
+    fn is_finite(self) -> bool {
+        half::f16::is_finite(self)
+    }
+}
@@ -0,0 +1,314 @@
+//! Mapping between Apple CoreVideo pixel format types and CoreGraphics color space names and `cint`
+//! pixel formats.
+//!
+//! On macOS and iOS, the pixels of a `CVPixelBuffer` are described by a four character
+//! `OSType` code like `'BGRA'` or `'420f'`, and their colors by the name of a `CGColorSpace`, like
+//! `kCGColorSpaceDisplayP3`, and, for Y'CbCr buffers, the `kCVImageBufferYCbCrMatrixKey`
+//! attachment. [`CoreVideoColorInfo`] collects these values so that
+//! [`CoreVideoColorInfo::pixel_format`] can translate them into a [`PixelFormat`], and
+//! [`CoreVideoColorInfo::from_pixel_format`] can translate them back, i.e. to configure an
+//! `AVCaptureVideoDataOutput` or the `colorspace` of a `CAMetalLayer`.
+
+use crate::{
+    AlphaState, ChromaSubsampling, ColorDescriptor, ComponentOrder, ComponentType, Error,
+    MatrixCoefficients, PixelFormat, PlaneLayout, SignalRange, SpaceRef, Spaces,
+};
+
+/// Build an `OSType` code from its four characters, as a `'BGRA'` literal does in C.
+pub const fn os_type(code: &[u8; 4]) -> u32 {
+    u32::from_be_bytes(*code)
+}
+
+#[allow(clippy::too_many_arguments)]
+const fn format(
+    space: Spaces,
+    alpha_state: AlphaState,
+    signal_range: SignalRange,
+    component_type: ComponentType,
+    bits_per_component: u8,
+    chroma_subsampling: ChromaSubsampling,
+    layout: PlaneLayout,
+    order: ComponentOrder,
+) -> PixelFormat {
+    PixelFormat {
+        descriptor: ColorDescriptor {
+            space: SpaceRef::Builtin(space),
+            alpha_state,
+            signal_range,
+        },
+        component_type,
+        bits_per_component,
+        chroma_subsampling,
+        layout,
+        order,
+        padded: false,
+        matrix_coefficients: None,
+    }
+}
+
+const fn rgb(
+    alpha_state: AlphaState,
+    component_type: ComponentType,
+    bits: u8,
+    order: ComponentOrder,
+) -> PixelFormat {
+    format(
+        Spaces::EncodedSrgb,
+        alpha_state,
+        SignalRange::Full,
+        component_type,
+        bits,
+        ChromaSubsampling::None,
+        PlaneLayout::Packed,
+        order,
+    )
+}
+
+const fn yuv(
+    signal_range: SignalRange,
+    bits: u8,
+    chroma_subsampling: ChromaSubsampling,
+    layout: PlaneLayout,
+    order: ComponentOrder,
+) -> PixelFormat {
+    format(
+        Spaces::YPrimeCbCr,
+        AlphaState::None,
+        signal_range,
+        ComponentType::Unsigned,
+        bits,
+        chroma_subsampling,
+        layout,
+        order,
+    )
+}
+
+const fn gray(component_type: ComponentType, bits: u8) -> PixelFormat {
+    format(
+        Spaces::Luma,
+        AlphaState::None,
+        SignalRange::Full,
+        component_type,
+        bits,
+        ChromaSubsampling::None,
+        PlaneLayout::Packed,
+        ComponentOrder::Native,
+    )
+}
+
+/// The known `kCVPixelFormatType_*` codes, in order of preference for mapping a [`PixelFormat`]
+/// back to a code.
+const PIXEL_FORMAT_TYPES: &[(u32, PixelFormat)] = {
+    use AlphaState::{None as Opaque, Straight};
+    use ChromaSubsampling::{Yuv420, Yuv422};
+    use ComponentOrder::*;
+    use ComponentType::{Float, Unsigned};
+    use PlaneLayout::*;
+    use SignalRange::{Full, Limited};
+
+    &[
+        (os_type(b"BGRA"), rgb(Straight, Unsigned, 8, Reversed)),
+        (os_type(b"RGBA"), rgb(Straight, Unsigned, 8, Native)),
+        (0x20, rgb(Straight, Unsigned, 8, AlphaFirst)),
+        (
+            os_type(b"ABGR"),
+            rgb(Straight, Unsigned, 8, ReversedAlphaFirst),
+        ),
+        (0x18, rgb(Opaque, Unsigned, 8, Native)),
+        (os_type(b"24BG"), rgb(Opaque, Unsigned, 8, Reversed)),
+        (os_type(b"l64r"), rgb(Straight, Unsigned, 16, Native)),
+        (os_type(b"RGhA"), rgb(Straight, Float, 16, Native)),
+        (os_type(b"RGfA"), rgb(Straight, Float, 32, Native)),
+        (
+            os_type(b"420v"),
+            yuv(Limited, 8, Yuv420, SemiPlanar, Native),
+        ),
+        (os_type(b"420f"), yuv(Full, 8, Yuv420, SemiPlanar, Native)),
+        (
+            os_type(b"422v"),
+            yuv(Limited, 8, Yuv422, SemiPlanar, Native),
+        ),
+        (os_type(b"422f"), yuv(Full, 8, Yuv422, SemiPlanar, Native)),
+        (
+            os_type(b"x420"),
+            yuv(Limited, 10, Yuv420, SemiPlanar, Native),
+        ),
+        (os_type(b"xf20"), yuv(Full, 10, Yuv420, SemiPlanar, Native)),
+        (
+            os_type(b"x422"),
+            yuv(Limited, 10, Yuv422, SemiPlanar, Native),
+        ),
+        (os_type(b"xf22"), yuv(Full, 10, Yuv422, SemiPlanar, Native)),
+        (
+            os_type(b"x444"),
+            yuv(Limited, 10, ChromaSubsampling::None, SemiPlanar, Native),
+        ),
+        (
+            os_type(b"xf44"),
+            yuv(Full, 10, ChromaSubsampling::None, SemiPlanar, Native),
+        ),
+        (os_type(b"y420"), yuv(Limited, 8, Yuv420, Planar, Native)),
+        (os_type(b"f420"), yuv(Full, 8, Yuv420, Planar, Native)),
+        (
+            os_type(b"2vuy"),
+            yuv(Limited, 8, Yuv422, Packed, ChromaFirst),
+        ),
+        (os_type(b"yuvs"), yuv(Limited, 8, Yuv422, Packed, Native)),
+        (os_type(b"L008"), gray(Unsigned, 8)),
+        (os_type(b"L016"), gray(Unsigned, 16)),
+        (os_type(b"L00h"), gray(Float, 16)),
+        (os_type(b"L00f"), gray(Float, 32)),
+    ]
+};
+
+/// The well-known `CGColorSpace` names, in order of preference for mapping a space back to a name.
+///
+/// `kCGColorSpaceDCIP3` uses the DCI white point rather than the D60 white point of
+/// [`Spaces::EncodedDciP3`], so it has no `cint` space.
+const CG_COLOR_SPACES: &[(&str, Spaces)] = &[
+    ("kCGColorSpaceSRGB", Spaces::EncodedSrgb),
+    ("kCGColorSpaceLinearSRGB", Spaces::LinearSrgb),
+    ("kCGColorSpaceDisplayP3", Spaces::EncodedDisplayP3),
+    ("kCGColorSpaceLinearDisplayP3", Spaces::DisplayP3),
+    ("kCGColorSpaceITUR_709", Spaces::EncodedRec709),
+    ("kCGColorSpaceITUR_2020", Spaces::EncodedBt2020),
+    ("kCGColorSpaceLinearITUR_2020", Spaces::Bt2020),
+    ("kCGColorSpaceITUR_2100_PQ", Spaces::EncodedBt2100PQ),
+    ("kCGColorSpaceITUR_2100_HLG", Spaces::EncodedBt2100HLG),
+    ("kCGColorSpaceDisplayP3_PQ", Spaces::EncodedDisplayP3PQ),
+    ("kCGColorSpaceAdobeRGB1998", Spaces::EncodedAdobeRgb1998),
+    ("kCGColorSpaceROMMRGB", Spaces::EncodedProPhotoRgb),
+    ("kCGColorSpaceACESCGLinear", Spaces::AcesCg),
+    ("kCGColorSpaceGenericXYZ", Spaces::CieXYZ),
+    ("kCGColorSpaceGenericLab", Spaces::CieLab),
+    ("kCGColorSpaceLinearGray", Spaces::Luminance),
+];
+
+/// The extended range `CGColorSpace` names, whose values may lie outside of `[0, 1]`. These are
+/// preferred over [`CG_COLOR_SPACES`] when mapping a floating point format back to a name.
+///
+/// `cint` has no separate extended range Display P3 or BT.2020 spaces, so these map to the same
+/// spaces as their standard range counterparts, and the range is carried by the floating point
+/// component type instead.
+const EXTENDED_CG_COLOR_SPACES: &[(&str, Spaces)] = &[
+    ("kCGColorSpaceExtendedSRGB", Spaces::EncodedExtendedSrgb),
+    ("kCGColorSpaceExtendedLinearSRGB", Spaces::ScRgb),
+    ("kCGColorSpaceExtendedDisplayP3", Spaces::EncodedDisplayP3),
+    ("kCGColorSpaceExtendedLinearDisplayP3", Spaces::DisplayP3),
+    ("kCGColorSpaceExtendedITUR_2020", Spaces::EncodedBt2020),
+    ("kCGColorSpaceExtendedLinearITUR_2020", Spaces::Bt2020),
+    ("kCGColorSpaceExtendedLinearGray", Spaces::Luminance),
+];
+
+/// The `kCVImageBufferYCbCrMatrix_*` attachment values.
+const YCBCR_MATRICES: &[(&str, MatrixCoefficients)] = &[
+    ("ITU_R_709_2", MatrixCoefficients::Bt709),
+    ("ITU_R_601_4", MatrixCoefficients::Bt601),
+    ("ITU_R_2020", MatrixCoefficients::Bt2020Ncl),
+];
+
+fn lookup<T: Copy>(table: &[(&str, T)], name: &str) -> Option<T> {
+    table
+        .iter()
+        .find(|(known, _)| *known == name)
+        .map(|(_, value)| *value)
+}
+
+fn reverse_lookup<T: PartialEq>(table: &[(&'static str, T)], value: &T) -> Option<&'static str> {
+    table
+        .iter()
+        .find(|(_, known)| known == value)
+        .map(|(name, _)| *name)
+}
+
+/// The pixel format type and color attachments of a `CVPixelBuffer`.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct CoreVideoColorInfo<'a> {
+    /// The `kCVPixelFormatType_*` code, i.e. `os_type(b"420f")`.
+    pub pixel_format_type: u32,
+    /// The name of the `CGColorSpace`, i.e. `"kCGColorSpaceDisplayP3"`, if known.
+    pub color_space: Option<&'a str>,
+    /// The `kCVImageBufferYCbCrMatrixKey` attachment, i.e. `"ITU_R_709_2"`, if known.
+    pub ycbcr_matrix: Option<&'a str>,
+}
+
+impl<'a> CoreVideoColorInfo<'a> {
+    /// Create a new [`CoreVideoColorInfo`] for the `pixel_format_type`, with unknown color
+    /// attachments.
+    pub fn new(pixel_format_type: u32) -> Self {
+        Self {
+            pixel_format_type,
+            color_space: None,
+            ycbcr_matrix: None,
+        }
+    }
+
+    /// Translate the values into a [`PixelFormat`].
+    ///
+    /// The `pixel_format_type` determines the structure and range of the pixels. RGB and gray
+    /// formats take their space from the `color_space` name, defaulting to [`Spaces::EncodedSrgb`]
+    /// and [`Spaces::Luma`], and Y'CbCr formats are described as [`Spaces::YPrimeCbCr`] with the
+    /// matrix given by `ycbcr_matrix`. Names which are not known are ignored.
+    ///
+    /// Fails with [`Error::UnsupportedFormat`] if the `pixel_format_type` is not known.
+    pub fn pixel_format(&self) -> Result<PixelFormat, Error> {
+        let (_, mut format) = *PIXEL_FORMAT_TYPES
+            .iter()
+            .find(|(code, _)| *code == self.pixel_format_type)
+            .ok_or(Error::UnsupportedFormat)?;
+
+        let default_space = format.descriptor.space;
+        if default_space == SpaceRef::Builtin(Spaces::YPrimeCbCr) {
+            format.matrix_coefficients = self
+                .ycbcr_matrix
+                .and_then(|name| lookup(YCBCR_MATRICES, name));
+        } else if let Some(space) = self.color_space.and_then(|name| {
+            lookup(CG_COLOR_SPACES, name).or_else(|| lookup(EXTENDED_CG_COLOR_SPACES, name))
+        }) {
+            // A gray color space can't describe an RGB format, or the other way around.
+            let is_gray = default_space == SpaceRef::Builtin(Spaces::Luma);
+            if is_gray == (space.component_names().len() == 1) {
+                format.descriptor.space = space.into();
+            }
+        }
+        Ok(format)
+    }
+}
+
+impl CoreVideoColorInfo<'static> {
+    /// Translate a [`PixelFormat`] into the values CoreVideo uses for it, the inverse of
+    /// [`CoreVideoColorInfo::pixel_format`]. Attachments which can't be named are `None`.
+    ///
+    /// Floating point formats are given the extended range `CGColorSpace` name of their space where
+    /// one exists, i.e. `kCGColorSpaceExtendedDisplayP3` rather than `kCGColorSpaceDisplayP3`.
+    ///
+    /// Fails with [`Error::UnsupportedFormat`] if the structure of the pixels doesn't match any
+    /// known `pixel_format_type`.
+    pub fn from_pixel_format(format: &PixelFormat) -> Result<Self, Error> {
+        let space = match format.descriptor.space {
+            SpaceRef::Builtin(space) => space,
+            SpaceRef::Custom(_) => return Err(Error::UnsupportedFormat),
+        };
+        let (pixel_format_type, _) = PIXEL_FORMAT_TYPES
+            .iter()
+            .find(|(_, known)| {
+                known.same_memory_layout(format)
+                    && (known.descriptor.space != SpaceRef::Builtin(Spaces::YPrimeCbCr)
+                        || known.descriptor.signal_range == format.descriptor.signal_range)
+            })
+            .ok_or(Error::UnsupportedFormat)?;
+
+        let mut info = CoreVideoColorInfo::new(*pixel_format_type);
+        info.color_space = if format.component_type == ComponentType::Float {
+            reverse_lookup(EXTENDED_CG_COLOR_SPACES, &space)
+                .or_else(|| reverse_lookup(CG_COLOR_SPACES, &space))
+        } else {
+            reverse_lookup(CG_COLOR_SPACES, &space)
+                .or_else(|| reverse_lookup(EXTENDED_CG_COLOR_SPACES, &space))
+        };
+        info.ycbcr_matrix = format
+            .matrix_coefficients
+            .and_then(|matrix_coefficients| reverse_lookup(YCBCR_MATRICES, &matrix_coefficients));
+        Ok(info)
+    }
+}
@@ -0,0 +1,44 @@
+use core::marker::PhantomData;
+
+use crate::{ColorType, Spaces};
+
+/// A color in a relative space, such as [`Hsl`][crate::Hsl] or [`YCbCr`][crate::YCbCr], which
+/// was derived from the RGB color type `BaseTy` rather than the space's default base, i.e.
+/// `DerivedFrom<Hsl<f32>, EncodedSrgb<f32>>` for HSL computed from encoded sRGB, as in CSS.
+///
+/// Only the space of `BaseTy` is meaningful; no values of it are stored. Colors that are not
+/// wrapped in [`DerivedFrom`] are assumed to be derived from the base documented on their space.
+///
+/// This has the same layout as `ColorTy` and passes through its [`ColorType`], array, and
+/// `bytemuck` impls.
+#[repr(transparent)]
+pub struct DerivedFrom<ColorTy: ColorType, BaseTy: ColorType> {
+    /// The contained color.
+    pub color: ColorTy,
+    _base: PhantomData<fn() -> BaseTy>,
+}
+
+impl<ColorTy: ColorType, BaseTy: ColorType> DerivedFrom<ColorTy, BaseTy> {
+    /// The space of the RGB color type the contained color was derived from.
+    pub const BASE_SPACE: Spaces = BaseTy::SPACE;
+
+    /// Mark `color` as derived from `BaseTy`.
+    pub const fn new(color: ColorTy) -> Self {
+        Self {
+            color,
+            _base: PhantomData,
+        }
+    }
+
+    /// Remove the base, returning the contained color.
+    pub fn into_inner(self) -> ColorTy {
+        self.color
+    }
+}
+
+impl_transparent_wrapper! {
+    DerivedFrom<ColorTy, BaseTy: ColorType> {
+        marker: _base,
+        debug: "base" => BaseTy::SPACE,
+    }
+}
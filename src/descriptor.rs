@@ -0,0 +1,80 @@
+use crate::{AlphaState, Chromaticity, SignalRange, Spaces, TransferFunction, WhitePoint};
+
+/// A description of a color space that is not one of the [`Spaces`] supported by the library,
+/// defined by a downstream crate.
+///
+/// Custom spaces are registered in a `ColorSpaceRegistry` (with the `alloc` feature), which
+/// hands out a [`CustomSpaceId`] that can be used to refer to them in a [`ColorDescriptor`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CustomColorSpace {
+    /// A globally unique identifier for the space, i.e. a reverse domain name like
+    /// `"com.example.my-camera-log"`.
+    pub id: &'static str,
+    /// The number of components of colors in the space.
+    pub num_components: usize,
+    /// The chromaticities of the red, green, and blue primaries of the space, in that order,
+    /// if it is an RGB space.
+    pub primaries: Option<[Chromaticity; 3]>,
+    /// The white point of the space, if known.
+    pub white_point: Option<WhitePoint>,
+    /// The transfer function of the space, if it is one of the known [`TransferFunction`]s.
+    pub transfer_function: Option<TransferFunction>,
+}
+
+/// A token referring to a [`CustomColorSpace`] registered in a `ColorSpaceRegistry`.
+///
+/// Tokens are only meaningful for the registry that handed them out.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct CustomSpaceId(pub(crate) u32);
+
+impl CustomSpaceId {
+    /// The index of the space within its registry.
+    pub fn index(self) -> u32 {
+        self.0
+    }
+}
+
+/// A color space which is either one of the [`Spaces`] supported by the library or a
+/// registered [`CustomColorSpace`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum SpaceRef {
+    /// One of the spaces supported by the library.
+    Builtin(Spaces),
+    /// A custom space registered at runtime.
+    Custom(CustomSpaceId),
+}
+
+impl From<Spaces> for SpaceRef {
+    fn from(space: Spaces) -> Self {
+        Self::Builtin(space)
+    }
+}
+
+impl From<CustomSpaceId> for SpaceRef {
+    fn from(id: CustomSpaceId) -> Self {
+        Self::Custom(id)
+    }
+}
+
+/// A runtime description of the colors in a buffer, i.e. for tagging decoded images or
+/// video frames with their color metadata.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct ColorDescriptor {
+    /// The color space of the colors.
+    pub space: SpaceRef,
+    /// Whether the colors have an alpha component, and if so, whether it is premultiplied.
+    pub alpha_state: AlphaState,
+    /// The range of code values used by the colors.
+    pub signal_range: SignalRange,
+}
+
+impl ColorDescriptor {
+    /// Create a new [`ColorDescriptor`] for full-range colors in `space` without an alpha component.
+    pub fn new(space: impl Into<SpaceRef>) -> Self {
+        Self {
+            space: space.into(),
+            alpha_state: AlphaState::None,
+            signal_range: SignalRange::Full,
+        }
+    }
+}
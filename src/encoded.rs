@@ -0,0 +1,170 @@
+use core::marker::PhantomData;
+
+use crate::{
+    Bt2020, Bt2100, Bt601_525, Bt601_625, ColorType, DciP3, DisplayP3, EncodedBt2020,
+    EncodedBt2100HLG, EncodedBt2100PQ, EncodedBt601_525, EncodedBt601_625, EncodedDciP3,
+    EncodedDisplayP3, EncodedDisplayP3PQ, EncodedExtendedSrgb, EncodedRec709, EncodedSrgb,
+    LinearSrgb, Rec709, ScRgb, Spaces, TransferFunction,
+};
+
+/// A type-level marker for a [`TransferFunction`], used as the `TF` parameter of [`Encoded`].
+pub trait TransferFunctionMarker {
+    /// The transfer function this marker stands for.
+    const TRANSFER_FUNCTION: TransferFunction;
+}
+
+/// Type-level markers for the transfer functions commonly used with [`Encoded`].
+pub mod transfer {
+    use super::TransferFunctionMarker;
+    use crate::TransferFunction;
+
+    macro_rules! transfer_markers {
+        ($($(#[$doc:meta])* $name:ident => $transfer_function:ident,)+) => {
+            $(
+                $(#[$doc])*
+                #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+                pub enum $name {}
+
+                impl TransferFunctionMarker for $name {
+                    const TRANSFER_FUNCTION: TransferFunction = TransferFunction::$transfer_function;
+                }
+            )+
+        };
+    }
+
+    transfer_markers! {
+        /// The marker for [`TransferFunction::Srgb`].
+        Srgb => Srgb,
+        /// The marker for [`TransferFunction::Bt709`].
+        Bt709 => Bt709,
+        /// The marker for [`TransferFunction::Pq`].
+        Pq => Pq,
+        /// The marker for [`TransferFunction::Hlg`].
+        Hlg => Hlg,
+        /// The marker for [`TransferFunction::Gamma22`].
+        Gamma22 => Gamma22,
+        /// The marker for [`TransferFunction::Dci`], a pure 2.6 gamma.
+        Gamma26 => Dci,
+    }
+}
+
+/// The concrete encoded space for colors in the linear space `linear` encoded with
+/// `transfer_function`, if `cint` has one.
+const fn encoded_space(linear: Spaces, transfer_function: TransferFunction) -> Option<Spaces> {
+    use TransferFunction::*;
+
+    let space = match (linear, transfer_function) {
+        (Spaces::LinearSrgb, Srgb) => Spaces::EncodedSrgb,
+        (Spaces::ScRgb, Srgb) => Spaces::EncodedExtendedSrgb,
+        (Spaces::Rec709, Bt709) => Spaces::EncodedRec709,
+        (Spaces::DisplayP3, Srgb) => Spaces::EncodedDisplayP3,
+        (Spaces::DisplayP3, Pq) => Spaces::EncodedDisplayP3PQ,
+        (Spaces::DciP3, Dci) => Spaces::EncodedDciP3,
+        (Spaces::Bt2020, Bt709) => Spaces::EncodedBt2020,
+        (Spaces::Bt2020 | Spaces::Bt2100, Pq) => Spaces::EncodedBt2100PQ,
+        (Spaces::Bt2020 | Spaces::Bt2100, Hlg) => Spaces::EncodedBt2100HLG,
+        (Spaces::Bt601_625, Bt709) => Spaces::EncodedBt601_625,
+        (Spaces::Bt601_525, Bt709) => Spaces::EncodedBt601_525,
+        _ => return None,
+    };
+    Some(space)
+}
+
+/// The generic space that best fits the given number of components: [`Spaces::GenericColor1`]
+/// for single-channel colors and [`Spaces::GenericColor3`] otherwise.
+const fn generic_space(num_components: usize) -> Spaces {
+    match num_components {
+        1 => Spaces::GenericColor1,
+        _ => Spaces::GenericColor3,
+    }
+}
+
+/// A color in the linear space of `ColorTy` whose components have been encoded with the transfer
+/// function `TF`, i.e. `Encoded<DisplayP3<f32>, transfer::Pq>` for PQ-encoded Display P3.
+///
+/// This allows any combination of a linear space and a transfer function to be expressed without a
+/// dedicated color type. Where `cint` does have a dedicated type for the combination, i.e.
+/// [`EncodedSrgb`] for `Encoded<LinearSrgb, transfer::Srgb>`, the two convert with `From`, and the
+/// [`ColorType::SPACE`] of the wrapper is that type's space. Otherwise, the space is the generic
+/// space with the same number of components (i.e. [`Spaces::GenericColor3`]), and consumers should
+/// use [`Encoded::LINEAR_SPACE`] and [`Encoded::TRANSFER_FUNCTION`] to interpret the colors.
+///
+/// This has the same layout as `ColorTy` and passes through its array and `bytemuck` impls.
+#[repr(transparent)]
+pub struct Encoded<ColorTy: ColorType, TF: TransferFunctionMarker> {
+    /// The contained color, whose components hold encoded values.
+    pub color: ColorTy,
+    _transfer_function: PhantomData<fn() -> TF>,
+}
+
+impl<ColorTy: ColorType, TF: TransferFunctionMarker> Encoded<ColorTy, TF> {
+    /// The linear space the contained color's components were encoded from.
+    pub const LINEAR_SPACE: Spaces = ColorTy::SPACE;
+
+    /// The [`TransferFunction`] the contained color's components were encoded with.
+    pub const TRANSFER_FUNCTION: TransferFunction = TF::TRANSFER_FUNCTION;
+
+    /// The dedicated encoded space for this combination, if `cint` has one.
+    pub const ENCODED_SPACE: Option<Spaces> = encoded_space(ColorTy::SPACE, TF::TRANSFER_FUNCTION);
+
+    /// Mark the components of `color` as encoded with `TF`.
+    pub const fn new(color: ColorTy) -> Self {
+        Self {
+            color,
+            _transfer_function: PhantomData,
+        }
+    }
+
+    /// Remove the transfer function, returning the contained color.
+    pub fn into_inner(self) -> ColorTy {
+        self.color
+    }
+}
+
+impl_transparent_wrapper! {
+    Encoded<ColorTy, TF: TransferFunctionMarker> {
+        marker: _transfer_function,
+        debug: "transfer_function" => TF::TRANSFER_FUNCTION,
+        space: match encoded_space(ColorTy::SPACE, TF::TRANSFER_FUNCTION) {
+            Some(space) => space,
+            None => generic_space(ColorTy::NUM_COMPONENTS),
+        },
+    }
+}
+
+/// Implements conversions between [`Encoded`] and the dedicated encoded RGB color types.
+macro_rules! impl_encoded_conversions {
+    ($($linear:ident + $transfer_function:ident <=> $encoded:ident,)+) => {
+        $(
+            impl<T: Copy> From<Encoded<$linear<T>, transfer::$transfer_function>> for $encoded<T> {
+                fn from(encoded: Encoded<$linear<T>, transfer::$transfer_function>) -> Self {
+                    let $linear { r, g, b } = encoded.color;
+                    $encoded { r, g, b }
+                }
+            }
+
+            impl<T: Copy> From<$encoded<T>> for Encoded<$linear<T>, transfer::$transfer_function> {
+                fn from(encoded: $encoded<T>) -> Self {
+                    let $encoded { r, g, b } = encoded;
+                    Encoded::new($linear { r, g, b })
+                }
+            }
+        )+
+    };
+}
+
+impl_encoded_conversions! {
+    LinearSrgb + Srgb <=> EncodedSrgb,
+    ScRgb + Srgb <=> EncodedExtendedSrgb,
+    Rec709 + Bt709 <=> EncodedRec709,
+    DisplayP3 + Srgb <=> EncodedDisplayP3,
+    DisplayP3 + Pq <=> EncodedDisplayP3PQ,
+    DciP3 + Gamma26 <=> EncodedDciP3,
+    Bt2020 + Bt709 <=> EncodedBt2020,
+    Bt2020 + Pq <=> EncodedBt2100PQ,
+    Bt2020 + Hlg <=> EncodedBt2100HLG,
+    Bt2100 + Pq <=> EncodedBt2100PQ,
+    Bt2100 + Hlg <=> EncodedBt2100HLG,
+    Bt601_625 + Bt709 <=> EncodedBt601_625,
+    Bt601_525 + Bt709 <=> EncodedBt601_525,
+}
@@ -0,0 +1,36 @@
+use core::fmt;
+
+/// The error type for fallible operations in `cint`.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum Error {
+    /// A color was created from the wrong number of components.
+    WrongComponentCount {
+        /// The number of components the color type has.
+        expected: usize,
+        /// The number of components that were provided.
+        found: usize,
+    },
+    /// A value was outside of the range representable by the target type.
+    OutOfRange,
+    /// A custom color space with the same id has already been registered.
+    AlreadyRegistered,
+    /// A color format or space from another crate has no equivalent in `cint`, or vice versa.
+    UnsupportedFormat,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Error::WrongComponentCount { expected, found } => {
+                write!(f, "expected {} components, found {}", expected, found)
+            }
+            Error::OutOfRange => f.write_str("value out of range"),
+            Error::AlreadyRegistered => f.write_str("color space id already registered"),
+            Error::UnsupportedFormat => f.write_str("unsupported color format"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
@@ -0,0 +1,269 @@
+use core::convert::TryFrom;
+
+use exr::block::samples::Sample;
+use exr::math::Vec2;
+use exr::meta::attribute::{ChannelList, Chromaticities};
+use exr::prelude::f16;
+
+use crate::{
+    Aces2065, AcesCg, AlphaState, Chromaticity, ColorDescriptor, Error, GenericColor3, LinearSrgb,
+    PremultipliedAlpha, Spaces, WhitePoint,
+};
+
+/// The linear RGB spaces which can be described by OpenEXR chromaticities, with their red, green,
+/// and blue primaries and white point.
+const KNOWN_CHROMATICITIES: [(Spaces, [Chromaticity; 3], WhitePoint); 9] = [
+    (
+        Spaces::LinearSrgb,
+        [
+            Chromaticity::new(0.64, 0.33),
+            Chromaticity::new(0.30, 0.60),
+            Chromaticity::new(0.15, 0.06),
+        ],
+        WhitePoint::D65,
+    ),
+    (
+        Spaces::DisplayP3,
+        [
+            Chromaticity::new(0.680, 0.320),
+            Chromaticity::new(0.265, 0.690),
+            Chromaticity::new(0.150, 0.060),
+        ],
+        WhitePoint::D65,
+    ),
+    (
+        Spaces::DciP3,
+        [
+            Chromaticity::new(0.680, 0.320),
+            Chromaticity::new(0.265, 0.690),
+            Chromaticity::new(0.150, 0.060),
+        ],
+        WhitePoint::D60,
+    ),
+    (
+        Spaces::Bt2020,
+        [
+            Chromaticity::new(0.708, 0.292),
+            Chromaticity::new(0.170, 0.797),
+            Chromaticity::new(0.131, 0.046),
+        ],
+        WhitePoint::D65,
+    ),
+    (
+        Spaces::Aces2065,
+        [
+            Chromaticity::new(0.7347, 0.2653),
+            Chromaticity::new(0.0, 1.0),
+            Chromaticity::new(0.0001, -0.077),
+        ],
+        WhitePoint::D60,
+    ),
+    (
+        Spaces::AcesCg,
+        [
+            Chromaticity::new(0.713, 0.293),
+            Chromaticity::new(0.165, 0.830),
+            Chromaticity::new(0.128, 0.044),
+        ],
+        WhitePoint::D60,
+    ),
+    (
+        Spaces::AdobeRgb1998,
+        [
+            Chromaticity::new(0.64, 0.33),
+            Chromaticity::new(0.21, 0.71),
+            Chromaticity::new(0.15, 0.06),
+        ],
+        WhitePoint::D65,
+    ),
+    (
+        Spaces::ProPhotoRgb,
+        [
+            Chromaticity::new(0.7347, 0.2653),
+            Chromaticity::new(0.1596, 0.8404),
+            Chromaticity::new(0.0366, 0.0001),
+        ],
+        WhitePoint::D50,
+    ),
+    (
+        Spaces::CieXYZ,
+        [
+            Chromaticity::new(1.0, 0.0),
+            Chromaticity::new(0.0, 1.0),
+            Chromaticity::new(0.0, 0.0),
+        ],
+        WhitePoint::E,
+    ),
+];
+
+fn approx_eq(chromaticity: Chromaticity, v: Vec2<f32>) -> bool {
+    (chromaticity.x - v.0).abs() < 0.001 && (chromaticity.y - v.1).abs() < 0.001
+}
+
+fn to_vec2(chromaticity: Chromaticity) -> Vec2<f32> {
+    Vec2(chromaticity.x, chromaticity.y)
+}
+
+/// Matches the chromaticities against the primaries and white point of the linear RGB spaces
+/// (and [`Spaces::CieXYZ`]), to within a tolerance of 0.001. Fails with
+/// [`Error::UnsupportedFormat`] if none of them match.
+impl TryFrom<Chromaticities> for Spaces {
+    type Error = Error;
+
+    fn try_from(chromaticities: Chromaticities) -> Result<Self, Error> {
+        KNOWN_CHROMATICITIES
+            .iter()
+            .find(|(_, [red, green, blue], white_point)| {
+                approx_eq(*red, chromaticities.red)
+                    && approx_eq(*green, chromaticities.green)
+                    && approx_eq(*blue, chromaticities.blue)
+                    && approx_eq(white_point.chromaticity(), chromaticities.white)
+            })
+            .map(|(space, _, _)| *space)
+            .ok_or(Error::UnsupportedFormat)
+    }
+}
+
+/// The inverse of the conversion from [`Chromaticities`]. [`Spaces::Rec709`] and [`Spaces::Bt2100`]
+/// are also accepted. Fails with [`Error::UnsupportedFormat`] for other spaces, since OpenEXR images
+/// hold linear RGB (or XYZ) data.
+impl TryFrom<Spaces> for Chromaticities {
+    type Error = Error;
+
+    fn try_from(space: Spaces) -> Result<Self, Error> {
+        let space = match space {
+            Spaces::Rec709 => Spaces::LinearSrgb,
+            Spaces::Bt2100 => Spaces::Bt2020,
+            space => space,
+        };
+        KNOWN_CHROMATICITIES
+            .iter()
+            .find(|(known, _, _)| *known == space)
+            .map(|(_, [red, green, blue], white_point)| Chromaticities {
+                red: to_vec2(*red),
+                green: to_vec2(*green),
+                blue: to_vec2(*blue),
+                white: to_vec2(white_point.chromaticity()),
+            })
+            .ok_or(Error::UnsupportedFormat)
+    }
+}
+
+impl ColorDescriptor {
+    /// Create a [`ColorDescriptor`] for the channels of an OpenEXR layer, i.e. from the
+    /// `channels` and `attributes.chromaticities` of an `exr` layer header.
+    ///
+    /// `R`, `G`, and `B` channels are described with the space given by `chromaticities`, which
+    /// defaults to [`Spaces::LinearSrgb`] (the Rec.709 primaries) as in the OpenEXR specification.
+    /// A lone `Y` channel is described as [`Spaces::Luminance`]. An `A` channel is described as
+    /// premultiplied alpha, since OpenEXR colors are always premultiplied. Layer prefixes like
+    /// `diffuse.R` are ignored.
+    ///
+    /// Fails with [`Error::UnsupportedFormat`] for other channel layouts, like luminance/chroma
+    /// (`Y`, `RY`, `BY`) images, and for unknown chromaticities.
+    pub fn from_exr_channels(
+        channels: &ChannelList,
+        chromaticities: Option<Chromaticities>,
+    ) -> Result<Self, Error> {
+        let (mut r, mut g, mut b, mut y, mut a) = (false, false, false, false, false);
+        for channel in channels.list.iter() {
+            let name = channel.name.as_slice();
+            let name = match name.iter().rposition(|&c| c == b'.') {
+                Some(dot) => &name[dot + 1..],
+                None => name,
+            };
+            match name {
+                b"R" => r = true,
+                b"G" => g = true,
+                b"B" => b = true,
+                b"Y" => y = true,
+                b"A" => a = true,
+                _ => return Err(Error::UnsupportedFormat),
+            }
+        }
+
+        let space = match (r && g && b, r || g || b, y) {
+            (true, _, false) => match chromaticities {
+                Some(chromaticities) => Spaces::try_from(chromaticities)?,
+                None => Spaces::LinearSrgb,
+            },
+            (false, false, true) => Spaces::Luminance,
+            _ => return Err(Error::UnsupportedFormat),
+        };
+        let alpha_state = if a {
+            AlphaState::Premultiplied
+        } else {
+            AlphaState::None
+        };
+        Ok(ColorDescriptor {
+            alpha_state,
+            ..ColorDescriptor::new(space)
+        })
+    }
+}
+
+macro_rules! impl_exr_samples {
+    ($($name:ident),+) => {
+        $(
+            impl From<[Sample; 3]> for $name<f32> {
+                fn from([x, y, z]: [Sample; 3]) -> Self {
+                    Self::from([x.to_f32(), y.to_f32(), z.to_f32()])
+                }
+            }
+
+            impl From<[Sample; 3]> for $name<f16> {
+                fn from([x, y, z]: [Sample; 3]) -> Self {
+                    Self::from([x.to_f16(), y.to_f16(), z.to_f16()])
+                }
+            }
+
+            impl From<$name<f32>> for [Sample; 3] {
+                fn from(color: $name<f32>) -> Self {
+                    let [x, y, z]: [f32; 3] = color.into();
+                    [Sample::F32(x), Sample::F32(y), Sample::F32(z)]
+                }
+            }
+
+            impl From<$name<f16>> for [Sample; 3] {
+                fn from(color: $name<f16>) -> Self {
+                    let [x, y, z]: [f16; 3] = color.into();
+                    [Sample::F16(x), Sample::F16(y), Sample::F16(z)]
+                }
+            }
+
+            impl From<[Sample; 4]> for PremultipliedAlpha<$name<f32>> {
+                fn from([x, y, z, alpha]: [Sample; 4]) -> Self {
+                    Self {
+                        color: [x, y, z].into(),
+                        alpha: alpha.to_f32(),
+                    }
+                }
+            }
+
+            impl From<[Sample; 4]> for PremultipliedAlpha<$name<f16>> {
+                fn from([x, y, z, alpha]: [Sample; 4]) -> Self {
+                    Self {
+                        color: [x, y, z].into(),
+                        alpha: alpha.to_f16(),
+                    }
+                }
+            }
+
+            impl From<PremultipliedAlpha<$name<f32>>> for [Sample; 4] {
+                fn from(color: PremultipliedAlpha<$name<f32>>) -> Self {
+                    let [x, y, z]: [Sample; 3] = color.color.into();
+                    [x, y, z, Sample::F32(color.alpha)]
+                }
+            }
+
+            impl From<PremultipliedAlpha<$name<f16>>> for [Sample; 4] {
+                fn from(color: PremultipliedAlpha<$name<f16>>) -> Self {
+                    let [x, y, z]: [Sample; 3] = color.color.into();
+                    [x, y, z, Sample::F16(color.alpha)]
+                }
+            }
+        )+
+    };
+}
+
+impl_exr_samples!(LinearSrgb, AcesCg, Aces2065, GenericColor3);
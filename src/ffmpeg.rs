@@ -0,0 +1,435 @@
+//! Mapping between FFmpeg's pixel format and color metadata names and `cint` pixel formats.
+//!
+//! FFmpeg describes the pixels of a frame with a `pix_fmt` name like `yuv420p` or `bgra`, and their
+//! colors with separate `colorspace` (matrix), `color_trc` (transfer), `color_primaries`, and
+//! `color_range` names, as used on the `ffmpeg` command line and by `ffprobe`. [`FfmpegColorInfo`]
+//! collects these names so that [`FfmpegColorInfo::pixel_format`] can translate them into a
+//! [`PixelFormat`], and [`FfmpegColorInfo::from_pixel_format`] can translate them back.
+
+use crate::{
+    AlphaState, ChromaSubsampling, ColorDescriptor, ComponentOrder, ComponentType, Error,
+    MatrixCoefficients, PixelFormat, PlaneLayout, SignalRange, SpaceRef, Spaces, TransferFunction,
+};
+
+#[allow(clippy::too_many_arguments)]
+const fn pix_fmt(
+    space: Spaces,
+    alpha_state: AlphaState,
+    signal_range: SignalRange,
+    component_type: ComponentType,
+    bits_per_component: u8,
+    chroma_subsampling: ChromaSubsampling,
+    layout: PlaneLayout,
+    order: ComponentOrder,
+) -> PixelFormat {
+    PixelFormat {
+        descriptor: ColorDescriptor {
+            space: SpaceRef::Builtin(space),
+            alpha_state,
+            signal_range,
+        },
+        component_type,
+        bits_per_component,
+        chroma_subsampling,
+        layout,
+        order,
+        padded: false,
+        matrix_coefficients: None,
+    }
+}
+
+const fn gray(bits: u8) -> PixelFormat {
+    pix_fmt(
+        Spaces::Luma,
+        AlphaState::None,
+        SignalRange::Full,
+        ComponentType::Unsigned,
+        bits,
+        ChromaSubsampling::None,
+        PlaneLayout::Packed,
+        ComponentOrder::Native,
+    )
+}
+
+const fn rgb(
+    alpha_state: AlphaState,
+    component_type: ComponentType,
+    bits: u8,
+    order: ComponentOrder,
+) -> PixelFormat {
+    pix_fmt(
+        Spaces::EncodedSrgb,
+        alpha_state,
+        SignalRange::Full,
+        component_type,
+        bits,
+        ChromaSubsampling::None,
+        PlaneLayout::Packed,
+        order,
+    )
+}
+
+const fn padded(format: PixelFormat) -> PixelFormat {
+    PixelFormat {
+        padded: true,
+        ..format
+    }
+}
+
+const fn yuv(
+    alpha_state: AlphaState,
+    signal_range: SignalRange,
+    bits: u8,
+    chroma_subsampling: ChromaSubsampling,
+    layout: PlaneLayout,
+    order: ComponentOrder,
+) -> PixelFormat {
+    pix_fmt(
+        Spaces::YPrimeCbCr,
+        alpha_state,
+        signal_range,
+        ComponentType::Unsigned,
+        bits,
+        chroma_subsampling,
+        layout,
+        order,
+    )
+}
+
+const fn yuv_planar(
+    signal_range: SignalRange,
+    bits: u8,
+    chroma_subsampling: ChromaSubsampling,
+) -> PixelFormat {
+    yuv(
+        AlphaState::None,
+        signal_range,
+        bits,
+        chroma_subsampling,
+        PlaneLayout::Planar,
+        ComponentOrder::Native,
+    )
+}
+
+const fn yuv_semi_planar(
+    bits: u8,
+    chroma_subsampling: ChromaSubsampling,
+    order: ComponentOrder,
+) -> PixelFormat {
+    yuv(
+        AlphaState::None,
+        SignalRange::Limited,
+        bits,
+        chroma_subsampling,
+        PlaneLayout::SemiPlanar,
+        order,
+    )
+}
+
+/// The known `pix_fmt` names, in order of preference for mapping a [`PixelFormat`] back to a name.
+/// Multi-byte formats are the little-endian variants.
+const PIX_FMTS: &[(&str, PixelFormat)] = {
+    use AlphaState::{None as Opaque, Straight};
+    use ChromaSubsampling::*;
+    use ComponentOrder::*;
+    use ComponentType::{Float, Unsigned};
+    use SignalRange::{Full, Limited};
+
+    &[
+        ("gray", gray(8)),
+        ("gray10le", gray(10)),
+        ("gray12le", gray(12)),
+        ("gray16le", gray(16)),
+        (
+            "grayf32le",
+            pix_fmt(
+                Spaces::Luminance,
+                Opaque,
+                Full,
+                Float,
+                32,
+                ChromaSubsampling::None,
+                PlaneLayout::Packed,
+                Native,
+            ),
+        ),
+        ("rgb24", rgb(Opaque, Unsigned, 8, Native)),
+        ("bgr24", rgb(Opaque, Unsigned, 8, Reversed)),
+        ("rgba", rgb(Straight, Unsigned, 8, Native)),
+        ("bgra", rgb(Straight, Unsigned, 8, Reversed)),
+        ("argb", rgb(Straight, Unsigned, 8, AlphaFirst)),
+        ("abgr", rgb(Straight, Unsigned, 8, ReversedAlphaFirst)),
+        ("rgb0", padded(rgb(Opaque, Unsigned, 8, Native))),
+        ("bgr0", padded(rgb(Opaque, Unsigned, 8, Reversed))),
+        ("0rgb", padded(rgb(Opaque, Unsigned, 8, AlphaFirst))),
+        ("0bgr", padded(rgb(Opaque, Unsigned, 8, ReversedAlphaFirst))),
+        ("rgb48le", rgb(Opaque, Unsigned, 16, Native)),
+        ("bgr48le", rgb(Opaque, Unsigned, 16, Reversed)),
+        ("rgba64le", rgb(Straight, Unsigned, 16, Native)),
+        ("bgra64le", rgb(Straight, Unsigned, 16, Reversed)),
+        ("rgbf32le", rgb(Opaque, Float, 32, Native)),
+        ("rgbaf32le", rgb(Straight, Float, 32, Native)),
+        ("yuv420p", yuv_planar(Limited, 8, Yuv420)),
+        ("yuv422p", yuv_planar(Limited, 8, Yuv422)),
+        ("yuv444p", yuv_planar(Limited, 8, ChromaSubsampling::None)),
+        ("yuv410p", yuv_planar(Limited, 8, Yuv410)),
+        ("yuv411p", yuv_planar(Limited, 8, Yuv411)),
+        ("yuv440p", yuv_planar(Limited, 8, Yuv440)),
+        ("yuvj420p", yuv_planar(Full, 8, Yuv420)),
+        ("yuvj422p", yuv_planar(Full, 8, Yuv422)),
+        ("yuvj444p", yuv_planar(Full, 8, ChromaSubsampling::None)),
+        ("yuvj440p", yuv_planar(Full, 8, Yuv440)),
+        ("yuv420p10le", yuv_planar(Limited, 10, Yuv420)),
+        ("yuv422p10le", yuv_planar(Limited, 10, Yuv422)),
+        (
+            "yuv444p10le",
+            yuv_planar(Limited, 10, ChromaSubsampling::None),
+        ),
+        ("yuv420p12le", yuv_planar(Limited, 12, Yuv420)),
+        ("yuv422p12le", yuv_planar(Limited, 12, Yuv422)),
+        (
+            "yuv444p12le",
+            yuv_planar(Limited, 12, ChromaSubsampling::None),
+        ),
+        ("yuv420p16le", yuv_planar(Limited, 16, Yuv420)),
+        ("yuv422p16le", yuv_planar(Limited, 16, Yuv422)),
+        (
+            "yuv444p16le",
+            yuv_planar(Limited, 16, ChromaSubsampling::None),
+        ),
+        (
+            "yuva420p",
+            yuv(Straight, Limited, 8, Yuv420, PlaneLayout::Planar, Native),
+        ),
+        (
+            "yuva422p",
+            yuv(Straight, Limited, 8, Yuv422, PlaneLayout::Planar, Native),
+        ),
+        (
+            "yuva444p",
+            yuv(
+                Straight,
+                Limited,
+                8,
+                ChromaSubsampling::None,
+                PlaneLayout::Planar,
+                Native,
+            ),
+        ),
+        ("nv12", yuv_semi_planar(8, Yuv420, Native)),
+        ("nv21", yuv_semi_planar(8, Yuv420, Reversed)),
+        ("nv16", yuv_semi_planar(8, Yuv422, Native)),
+        ("nv24", yuv_semi_planar(8, ChromaSubsampling::None, Native)),
+        (
+            "nv42",
+            yuv_semi_planar(8, ChromaSubsampling::None, Reversed),
+        ),
+        ("p010le", yuv_semi_planar(10, Yuv420, Native)),
+        ("p012le", yuv_semi_planar(12, Yuv420, Native)),
+        ("p016le", yuv_semi_planar(16, Yuv420, Native)),
+        ("p210le", yuv_semi_planar(10, Yuv422, Native)),
+        (
+            "p410le",
+            yuv_semi_planar(10, ChromaSubsampling::None, Native),
+        ),
+        (
+            "yuyv422",
+            yuv(Opaque, Limited, 8, Yuv422, PlaneLayout::Packed, Native),
+        ),
+        (
+            "yvyu422",
+            yuv(Opaque, Limited, 8, Yuv422, PlaneLayout::Packed, Reversed),
+        ),
+        (
+            "uyvy422",
+            yuv(Opaque, Limited, 8, Yuv422, PlaneLayout::Packed, ChromaFirst),
+        ),
+    ]
+};
+
+/// The names of the `colorspace` values, with the canonical name of each matrix first.
+const COLOR_SPACES: &[(&str, MatrixCoefficients)] = &[
+    ("gbr", MatrixCoefficients::Identity),
+    ("bt709", MatrixCoefficients::Bt709),
+    ("smpte170m", MatrixCoefficients::Bt601),
+    ("bt470bg", MatrixCoefficients::Bt601),
+    ("ycgco", MatrixCoefficients::YCoCg),
+    ("bt2020nc", MatrixCoefficients::Bt2020Ncl),
+    ("bt2020_ncl", MatrixCoefficients::Bt2020Ncl),
+    ("bt2020c", MatrixCoefficients::Bt2020Cl),
+    ("bt2020_cl", MatrixCoefficients::Bt2020Cl),
+    ("ictcp", MatrixCoefficients::ICtCp),
+];
+
+/// The names of the `color_trc` values, with the canonical name of each transfer function first.
+const COLOR_TRCS: &[(&str, TransferFunction)] = &[
+    ("bt709", TransferFunction::Bt709),
+    ("smpte170m", TransferFunction::Bt709),
+    ("bt2020-10", TransferFunction::Bt709),
+    ("bt2020-12", TransferFunction::Bt709),
+    ("linear", TransferFunction::Linear),
+    ("iec61966-2-1", TransferFunction::Srgb),
+    ("srgb", TransferFunction::Srgb),
+    ("smpte2084", TransferFunction::Pq),
+    ("arib-std-b67", TransferFunction::Hlg),
+    ("smpte428", TransferFunction::Dci),
+    ("bt470m", TransferFunction::Gamma22),
+    ("gamma22", TransferFunction::Gamma22),
+];
+
+/// Primaries are identified by the linear RGB space with those primaries.
+const COLOR_PRIMARIES: &[(&str, Spaces)] = &[
+    ("bt709", Spaces::LinearSrgb),
+    ("bt470bg", Spaces::Bt601_625),
+    ("smpte170m", Spaces::Bt601_525),
+    ("bt2020", Spaces::Bt2020),
+    ("smpte432", Spaces::DisplayP3),
+    ("smpte428", Spaces::CieXYZ),
+];
+
+fn lookup<T: Copy>(table: &[(&str, T)], name: &str) -> Option<T> {
+    table
+        .iter()
+        .find(|(known, _)| *known == name)
+        .map(|(_, value)| *value)
+}
+
+fn reverse_lookup<T: PartialEq>(table: &[(&'static str, T)], value: &T) -> Option<&'static str> {
+    table
+        .iter()
+        .find(|(_, known)| known == value)
+        .map(|(name, _)| *name)
+}
+
+/// The RGB space with the given linear space's primaries and the given transfer function.
+fn rgb_space(primaries: Spaces, transfer_function: TransferFunction) -> Option<Spaces> {
+    use TransferFunction::*;
+
+    let space = match (primaries, transfer_function) {
+        (Spaces::LinearSrgb, Linear) => Spaces::LinearSrgb,
+        (Spaces::LinearSrgb, Srgb) => Spaces::EncodedSrgb,
+        (Spaces::LinearSrgb, Bt709) => Spaces::EncodedRec709,
+        (Spaces::Bt601_625, Linear) => Spaces::Bt601_625,
+        (Spaces::Bt601_625, Bt709) => Spaces::EncodedBt601_625,
+        (Spaces::Bt601_525, Linear) => Spaces::Bt601_525,
+        (Spaces::Bt601_525, Bt709) => Spaces::EncodedBt601_525,
+        (Spaces::Bt2020, Linear) => Spaces::Bt2020,
+        (Spaces::Bt2020, Bt709) => Spaces::EncodedBt2020,
+        (Spaces::Bt2020, Pq) => Spaces::EncodedBt2100PQ,
+        (Spaces::Bt2020, Hlg) => Spaces::EncodedBt2100HLG,
+        (Spaces::DisplayP3, Linear) => Spaces::DisplayP3,
+        (Spaces::DisplayP3, Srgb) => Spaces::EncodedDisplayP3,
+        (Spaces::DisplayP3, Pq) => Spaces::EncodedDisplayP3PQ,
+        (Spaces::CieXYZ, Linear) => Spaces::CieXYZ,
+        (Spaces::CieXYZ, Dci) => Spaces::DciXYZPrime,
+        _ => return None,
+    };
+    Some(space)
+}
+
+/// The inverse of [`rgb_space`].
+fn rgb_space_parts(space: Spaces) -> Option<(Spaces, TransferFunction)> {
+    COLOR_PRIMARIES.iter().find_map(|&(_, primaries)| {
+        COLOR_TRCS
+            .iter()
+            .map(|&(_, transfer_function)| transfer_function)
+            .find(|&transfer_function| rgb_space(primaries, transfer_function) == Some(space))
+            .map(|transfer_function| (primaries, transfer_function))
+    })
+}
+
+/// The pixel format and color metadata names of an FFmpeg frame or stream.
+///
+/// Names which are not known to the mapping, including FFmpeg's `"unknown"`, are ignored, and the
+/// defaults of the pixel format are used instead.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct FfmpegColorInfo<'a> {
+    /// The `pix_fmt` name, i.e. `"yuv420p"`.
+    pub pix_fmt: &'a str,
+    /// The `colorspace` (matrix) name, i.e. `"bt709"`.
+    pub color_space: &'a str,
+    /// The `color_trc` (transfer characteristics) name, i.e. `"iec61966-2-1"`.
+    pub color_trc: &'a str,
+    /// The `color_primaries` name, i.e. `"bt2020"`.
+    pub color_primaries: &'a str,
+    /// The `color_range` name, i.e. `"tv"` or `"pc"`.
+    pub color_range: &'a str,
+}
+
+impl<'a> FfmpegColorInfo<'a> {
+    /// Create a new [`FfmpegColorInfo`] for the `pix_fmt` name, with unknown color metadata.
+    pub fn new(pix_fmt: &'a str) -> Self {
+        Self {
+            pix_fmt,
+            color_space: "unknown",
+            color_trc: "unknown",
+            color_primaries: "unknown",
+            color_range: "unknown",
+        }
+    }
+
+    /// Translate the names into a [`PixelFormat`].
+    ///
+    /// The `pix_fmt` determines the structure of the pixels. RGB formats default to
+    /// [`Spaces::EncodedSrgb`], which is refined by `color_primaries` and `color_trc` if both are
+    /// known, and Y'CbCr formats are described as [`Spaces::YPrimeCbCr`] with the matrix given by
+    /// `colorspace`. `color_range` overrides the range implied by the `pix_fmt`, i.e. limited range
+    /// for `yuv420p` and full range for `yuvj420p`.
+    ///
+    /// Fails with [`Error::UnsupportedFormat`] if the `pix_fmt` is not known.
+    pub fn pixel_format(&self) -> Result<PixelFormat, Error> {
+        let mut format = lookup(PIX_FMTS, self.pix_fmt).ok_or(Error::UnsupportedFormat)?;
+
+        if format.descriptor.space == SpaceRef::Builtin(Spaces::YPrimeCbCr) {
+            format.matrix_coefficients = lookup(COLOR_SPACES, self.color_space);
+        } else if format.descriptor.space == SpaceRef::Builtin(Spaces::EncodedSrgb) {
+            let primaries = lookup(COLOR_PRIMARIES, self.color_primaries);
+            let transfer_function = lookup(COLOR_TRCS, self.color_trc);
+            if let Some(space) = primaries
+                .zip(transfer_function)
+                .and_then(|(primaries, transfer_function)| rgb_space(primaries, transfer_function))
+            {
+                format.descriptor.space = space.into();
+            }
+        }
+        match self.color_range {
+            "tv" | "mpeg" | "limited" => format.descriptor.signal_range = SignalRange::Limited,
+            "pc" | "jpeg" | "full" => format.descriptor.signal_range = SignalRange::Full,
+            _ => {}
+        }
+        Ok(format)
+    }
+}
+
+impl FfmpegColorInfo<'static> {
+    /// Translate a [`PixelFormat`] into the names FFmpeg uses for it, the inverse of
+    /// [`FfmpegColorInfo::pixel_format`]. Metadata which can't be named is `"unknown"`.
+    ///
+    /// Fails with [`Error::UnsupportedFormat`] if the structure of the pixels doesn't match any
+    /// known `pix_fmt`.
+    pub fn from_pixel_format(format: &PixelFormat) -> Result<Self, Error> {
+        let space = match format.descriptor.space {
+            SpaceRef::Builtin(space) => space,
+            SpaceRef::Custom(_) => return Err(Error::UnsupportedFormat),
+        };
+        let (pix_fmt, _) = PIX_FMTS
+            .iter()
+            .find(|(_, known)| known.same_memory_layout(format))
+            .ok_or(Error::UnsupportedFormat)?;
+
+        let mut info = FfmpegColorInfo::new(pix_fmt);
+        if let Some(matrix_coefficients) = format.matrix_coefficients {
+            info.color_space =
+                reverse_lookup(COLOR_SPACES, &matrix_coefficients).unwrap_or("unknown");
+        }
+        if let Some((primaries, transfer_function)) = rgb_space_parts(space) {
+            info.color_primaries = reverse_lookup(COLOR_PRIMARIES, &primaries).unwrap_or("unknown");
+            info.color_trc = reverse_lookup(COLOR_TRCS, &transfer_function).unwrap_or("unknown");
+        }
+        info.color_range = match format.descriptor.signal_range {
+            SignalRange::Limited => "tv",
+            SignalRange::Full => "pc",
+        };
+        Ok(info)
+    }
+}
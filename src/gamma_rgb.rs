@@ -0,0 +1,90 @@
+use crate::{
+    Chromaticity, CustomColorSpace, TransferFunction, TransferFunctionParameters, WhitePoint,
+};
+
+/// The runtime definition of the space of [`GammaRgb`][crate::GammaRgb] colors: "these primaries
+/// with gamma γ".
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GammaRgbParameters {
+    /// The chromaticities of the red, green, and blue primaries, in that order.
+    pub primaries: [Chromaticity; 3],
+    /// The white point of the space.
+    pub white_point: WhitePoint,
+    /// The exponent of the transfer function when decoding, i.e. 2.2 for a space whose encoded
+    /// values are linear values raised to the power of 1 / 2.2.
+    pub gamma: f32,
+}
+
+impl GammaRgbParameters {
+    /// Create a new [`GammaRgbParameters`] from the primaries, white point, and decoding gamma.
+    pub const fn new(primaries: [Chromaticity; 3], white_point: WhitePoint, gamma: f32) -> Self {
+        Self {
+            primaries,
+            white_point,
+            gamma,
+        }
+    }
+
+    /// Create a new [`GammaRgbParameters`] from the values of a PNG `cHRM` chunk (white point x
+    /// and y, then red, green, and blue x and y, each times 100000) and `gAMA` chunk (the encoding
+    /// exponent times 100000, i.e. 45455 for a decoding gamma of 2.2).
+    ///
+    /// Returns `None` if `gama` is zero, which the PNG specification does not allow.
+    pub fn from_png(chrm: [u32; 8], gama: u32) -> Option<Self> {
+        if gama == 0 {
+            return None;
+        }
+        let chromaticity =
+            |i: usize| Chromaticity::new(chrm[i] as f32 / 100000.0, chrm[i + 1] as f32 / 100000.0);
+        Some(Self {
+            primaries: [chromaticity(2), chromaticity(4), chromaticity(6)],
+            white_point: WhitePoint::Custom(chromaticity(0)),
+            gamma: 100000.0 / gama as f32,
+        })
+    }
+
+    /// The defining constants of the transfer function.
+    pub fn transfer_function_parameters(&self) -> TransferFunctionParameters {
+        TransferFunctionParameters::Gamma(self.gamma as f64)
+    }
+
+    /// The [`TransferFunction`] with this gamma, if there is one, i.e.
+    /// [`TransferFunction::Gamma22`] for a gamma of 2.2.
+    ///
+    /// The gamma of Adobe RGB (1998), 563 / 256, is within 0.0008 of 2.2, so gammas are matched
+    /// with a tolerance well below that, which still covers the rounding of a PNG `gAMA` chunk.
+    pub fn transfer_function(&self) -> Option<TransferFunction> {
+        const TOLERANCE: f32 = 0.0003;
+
+        [
+            TransferFunction::AdobeRgb1998,
+            TransferFunction::Gamma22,
+            TransferFunction::Bt1886,
+            TransferFunction::Dci,
+        ]
+        .iter()
+        .copied()
+        .find(|transfer_function| match transfer_function.parameters() {
+            TransferFunctionParameters::Gamma(gamma) => {
+                let difference = gamma as f32 - self.gamma;
+                difference < TOLERANCE && difference > -TOLERANCE
+            }
+            _ => false,
+        })
+    }
+
+    /// Describe the space as a [`CustomColorSpace`] with the given `id`, i.e. to register it in a
+    /// `ColorSpaceRegistry` and refer to it from a [`ColorDescriptor`][crate::ColorDescriptor].
+    ///
+    /// The transfer function of the result is only known if the gamma matches one of the
+    /// [`TransferFunction`]s, see [`GammaRgbParameters::transfer_function`].
+    pub fn custom_color_space(&self, id: &'static str) -> CustomColorSpace {
+        CustomColorSpace {
+            id,
+            num_components: 3,
+            primaries: Some(self.primaries),
+            white_point: Some(self.white_point),
+            transfer_function: self.transfer_function(),
+        }
+    }
+}
@@ -0,0 +1,241 @@
+#[cfg(feature = "bytemuck")]
+use bytemuck::{Pod, Zeroable};
+
+use crate::{Chromaticity, ColorInterop, ColorType, Spaces};
+
+/// Static metadata describing the display that HDR content was mastered on, as defined
+/// in SMPTE ST 2086.
+///
+/// This is commonly signaled alongside [`Spaces::EncodedBt2100PQ`][crate::Spaces::EncodedBt2100PQ]
+/// content as part of HDR10.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct MasteringDisplayMetadata {
+    /// The chromaticities of the red, green, and blue primaries of the mastering display,
+    /// in that order.
+    pub primaries: [Chromaticity; 3],
+    /// The chromaticity of the white point of the mastering display.
+    pub white_point: Chromaticity,
+    /// The maximum luminance of the mastering display, in nits (cd/m²).
+    pub max_luminance: f32,
+    /// The minimum luminance of the mastering display, in nits (cd/m²).
+    pub min_luminance: f32,
+}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl Zeroable for MasteringDisplayMetadata {}
+#[cfg(feature = "bytemuck")]
+unsafe impl Pod for MasteringDisplayMetadata {}
+
+/// Static metadata describing the light level of HDR content, as defined in CTA-861.3.
+///
+/// This is commonly signaled alongside [`Spaces::EncodedBt2100PQ`][crate::Spaces::EncodedBt2100PQ]
+/// content as part of HDR10.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, PartialOrd, Eq, Ord, Default)]
+#[repr(C)]
+pub struct ContentLightLevel {
+    /// The maximum content light level (MaxCLL), the luminance of the brightest pixel
+    /// in the content, in nits (cd/m²).
+    pub max_content_light_level: u16,
+    /// The maximum frame-average light level (MaxFALL), the highest average luminance
+    /// of any single frame in the content, in nits (cd/m²).
+    pub max_frame_average_light_level: u16,
+}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl Zeroable for ContentLightLevel {}
+#[cfg(feature = "bytemuck")]
+unsafe impl Pod for ContentLightLevel {}
+
+/// A color whose linear components represent absolute luminance, where a component value of 1.0
+/// corresponds to `reference_white_nits` nits (cd/m²).
+///
+/// This can be used to distinguish absolute scene- or display-referred data from relative data,
+/// i.e. an [`ScRgb`][crate::ScRgb] color is `Absolute` with a `reference_white_nits` of 80.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct Absolute<ColorTy: ColorType> {
+    /// The contained color.
+    pub color: ColorTy,
+    /// The luminance, in nits (cd/m²), represented by a component value of 1.0.
+    pub reference_white_nits: f32,
+}
+
+impl<ColorTy: ColorType> Absolute<ColorTy> {
+    /// Create a new [`Absolute`] color where a component value of 1.0 represents
+    /// `reference_white_nits` nits.
+    pub fn new(color: ColorTy, reference_white_nits: f32) -> Self {
+        Self {
+            color,
+            reference_white_nits,
+        }
+    }
+}
+
+impl<BaseColorTy: ColorType> ColorType for Absolute<BaseColorTy> {
+    type ComponentTy = BaseColorTy::ComponentTy;
+    const SPACE: Spaces = BaseColorTy::SPACE;
+    const NUM_COMPONENTS: usize = BaseColorTy::NUM_COMPONENTS;
+}
+
+impl<ColorTy: ColorType> ColorInterop for Absolute<ColorTy> {
+    type CintTy = Self;
+}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<ColorTy: ColorType + Zeroable> Zeroable for Absolute<ColorTy> {}
+
+/// The maximum number of anchor points in a [`ToneMappingCurve`], matching SMPTE ST 2094-40 (HDR10+).
+pub const MAX_TONE_MAPPING_ANCHORS: usize = 15;
+
+/// A tone mapping curve, described as a knee point followed by a Bézier curve through a series of anchors,
+/// as in SMPTE ST 2094-40 (HDR10+).
+///
+/// All values are normalized from 0 to 1, relative to the maximum luminance of the content (for inputs)
+/// and the targeted display (for outputs).
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct ToneMappingCurve {
+    /// The normalized input luminance of the knee point, below which the curve is linear.
+    pub knee_point_x: f32,
+    /// The normalized output luminance of the knee point.
+    pub knee_point_y: f32,
+    /// The anchor points of the curve above the knee point. Only the first `num_anchors` are meaningful.
+    pub anchors: [f32; MAX_TONE_MAPPING_ANCHORS],
+    /// The number of meaningful values in `anchors`.
+    pub num_anchors: u8,
+}
+
+impl ToneMappingCurve {
+    /// The meaningful anchor points of the curve.
+    pub fn anchors(&self) -> &[f32] {
+        let num_anchors = (self.num_anchors as usize).min(MAX_TONE_MAPPING_ANCHORS);
+        &self.anchors[..num_anchors]
+    }
+}
+
+/// Dynamic metadata describing a single frame or scene of HDR content, as signaled by formats like
+/// HDR10+ (SMPTE ST 2094-40) and Dolby Vision (SMPTE ST 2094-10).
+///
+/// This is meant to accompany [`Spaces::EncodedBt2100PQ`][crate::Spaces::EncodedBt2100PQ] or
+/// [`Spaces::EncodedBt2100HLG`][crate::Spaces::EncodedBt2100HLG] content alongside the static
+/// [`MasteringDisplayMetadata`] and [`ContentLightLevel`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct DynamicHdrMetadata {
+    /// The minimum luminance of the scene, in nits (cd/m²).
+    pub scene_min_luminance: f32,
+    /// The maximum luminance of the scene, in nits (cd/m²).
+    pub scene_max_luminance: f32,
+    /// The average luminance of the scene, in nits (cd/m²).
+    pub scene_average_luminance: f32,
+    /// The maximum luminance of the display that `tone_mapping` targets, in nits (cd/m²).
+    pub target_display_max_luminance: f32,
+    /// The recommended tone mapping curve for the targeted display, if any.
+    pub tone_mapping: Option<ToneMappingCurve>,
+}
+
+/// Whether linear color data represents light in the original scene or light emitted by a display.
+///
+/// Compositing and tone mapping treat these differently: scene-referred data usually needs to be
+/// tone mapped before display, while display-referred data is already meant to be shown as-is.
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum DynamicRangeIntent {
+    /// The data represents (relative or absolute) light in the original scene, as captured by a camera
+    /// or rendered by a renderer, and has an unbounded dynamic range.
+    SceneReferred,
+    /// The data represents light emitted by a (real or reference) display.
+    DisplayReferred,
+}
+
+impl Spaces {
+    /// The [`DynamicRangeIntent`] that data in this space conventionally has, or `None` if the space
+    /// is commonly used for both scene- and display-referred data (i.e. linear RGB spaces or CIE XYZ).
+    ///
+    /// Spaces encoded with a camera OETF, like [`Spaces::EncodedRec709`] or
+    /// [`Spaces::EncodedBt2020`], are scene-referred, while their display counterparts, like
+    /// [`Spaces::EncodedBt1886`], are display-referred.
+    ///
+    /// Use [`ReferredAs`] to tag data whose intent differs from (or is not implied by) its space.
+    pub fn referred(&self) -> Option<DynamicRangeIntent> {
+        match *self {
+            Self::AcesCg
+            | Self::Aces2065
+            | Self::AcesCc
+            | Self::AcesCct
+            | Self::EncodedRec709
+            | Self::EncodedBt601_625
+            | Self::EncodedBt601_525
+            | Self::EncodedBt2020
+            | Self::EncodedBt2100HLG
+            | Self::ICtCpHLG
+            | Self::ArriLogC3
+            | Self::ArriLogC4
+            | Self::SonySLog3
+            | Self::SonySLog3Cine
+            | Self::PanasonicVLog
+            | Self::RedLog3G10
+            | Self::CanonLog3
+            | Self::BlackmagicFilmGen5
+            | Self::DaVinciIntermediate
+            | Self::FilmLightTLogEGamut
+            | Self::DjiDLog
+            | Self::GoProProtune
+            | Self::CineonLog
+            | Self::FujiFLog
+            | Self::FujiFLog2
+            | Self::NikonNLog => Some(DynamicRangeIntent::SceneReferred),
+            Self::EncodedSrgb
+            | Self::EncodedDisplayP3
+            | Self::DciXYZPrime
+            | Self::EncodedBt2100PQ
+            | Self::ICtCpPQ
+            | Self::EncodedProPhotoRgb
+            | Self::EncodedAdobeRgb1998
+            | Self::EncodedWideGamutRgb
+            | Self::EncodedDisplayP3PQ
+            | Self::EncodedDciP3
+            | Self::ScRgb
+            | Self::EncodedExtendedSrgb
+            | Self::EncodedBt1886 => Some(DynamicRangeIntent::DisplayReferred),
+            _ => None,
+        }
+    }
+}
+
+/// A color tagged with an explicit [`DynamicRangeIntent`], overriding the default implied by
+/// [`Spaces::referred`] for its space.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+#[repr(C)]
+pub struct ReferredAs<ColorTy: ColorType> {
+    /// The contained color.
+    pub color: ColorTy,
+    /// Whether the contained color is scene- or display-referred.
+    pub intent: DynamicRangeIntent,
+}
+
+impl<ColorTy: ColorType> ReferredAs<ColorTy> {
+    /// Tag `color` with the given [`DynamicRangeIntent`].
+    pub fn new(color: ColorTy, intent: DynamicRangeIntent) -> Self {
+        Self { color, intent }
+    }
+
+    /// Tag `color` as scene-referred.
+    pub fn scene_referred(color: ColorTy) -> Self {
+        Self::new(color, DynamicRangeIntent::SceneReferred)
+    }
+
+    /// Tag `color` as display-referred.
+    pub fn display_referred(color: ColorTy) -> Self {
+        Self::new(color, DynamicRangeIntent::DisplayReferred)
+    }
+}
+
+impl<BaseColorTy: ColorType> ColorType for ReferredAs<BaseColorTy> {
+    type ComponentTy = BaseColorTy::ComponentTy;
+    const SPACE: Spaces = BaseColorTy::SPACE;
+    const NUM_COMPONENTS: usize = BaseColorTy::NUM_COMPONENTS;
+}
+
+impl<ColorTy: ColorType> ColorInterop for ReferredAs<ColorTy> {
+    type CintTy = Self;
+}
@@ -0,0 +1,136 @@
+#[cfg(feature = "bytemuck")]
+use bytemuck::{Pod, Zeroable};
+
+use crate::{CieLCh, ColorInterop, ColorRepr, ColorType, Oklch, Spaces};
+
+/// A hue angle in radians, varying from -PI to PI.
+///
+/// This is the unit of the hue component of cylindrical spaces like [`Oklch`] and [`CieLCh`].
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, PartialOrd, Eq, Ord)]
+#[repr(transparent)]
+pub struct Radians<T>(pub T);
+
+/// A hue angle in degrees, varying from 0 to 360.
+///
+/// This is the unit of the hue component of cylindrical colors wrapped in [`HueDegrees`].
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, PartialOrd, Eq, Ord)]
+#[repr(transparent)]
+pub struct Degrees<T>(pub T);
+
+/// A cylindrical color, such as [`Oklch`] or [`CieLCh`], whose hue component is given in
+/// [`Degrees`] from 0 to 360 rather than in [`Radians`] from -PI to PI, i.e. as used by CSS
+/// `oklch()` and `lch()` colors.
+///
+/// Colors that are not wrapped in [`HueDegrees`] are assumed to give their hue in radians.
+/// `From` converts between the two, and the `hue` accessors return the hue tagged with its unit.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, PartialOrd, Eq, Ord)]
+#[repr(transparent)]
+pub struct HueDegrees<ColorTy: ColorType> {
+    /// The contained color, whose hue component is in degrees.
+    pub color: ColorTy,
+}
+
+impl<BaseColorTy: ColorType> ColorType for HueDegrees<BaseColorTy> {
+    type ComponentTy = BaseColorTy::ComponentTy;
+    const SPACE: Spaces = BaseColorTy::SPACE;
+    const NUM_COMPONENTS: usize = BaseColorTy::NUM_COMPONENTS;
+}
+
+impl<ColorTy: ColorType> ColorInterop for HueDegrees<ColorTy> {
+    type CintTy = Self;
+}
+
+impl<ColorTy: ColorType + AsRef<T>, T: ?Sized> AsRef<T> for HueDegrees<ColorTy> {
+    fn as_ref(&self) -> &T {
+        self.color.as_ref()
+    }
+}
+
+impl<ColorTy: ColorType + AsMut<T>, T: ?Sized> AsMut<T> for HueDegrees<ColorTy> {
+    fn as_mut(&mut self) -> &mut T {
+        self.color.as_mut()
+    }
+}
+
+// SAFETY: same layout is guaranteed by repr transparent
+unsafe impl<ColorTy: ColorRepr> ColorRepr for HueDegrees<ColorTy> {
+    type Repr = ColorTy::Repr;
+    type AlphaRepr = ColorTy::AlphaRepr;
+}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<ColorTy: ColorType + Zeroable> Zeroable for HueDegrees<ColorTy> {}
+#[cfg(feature = "bytemuck")]
+unsafe impl<ColorTy: ColorType + Pod> Pod for HueDegrees<ColorTy> {}
+
+macro_rules! impl_hue_units {
+    ($($float_ty:ident),+) => {
+        $(
+            impl From<Radians<$float_ty>> for Degrees<$float_ty> {
+                fn from(Radians(radians): Radians<$float_ty>) -> Degrees<$float_ty> {
+                    let degrees = radians.to_degrees();
+                    Degrees(if degrees < 0.0 { degrees + 360.0 } else { degrees })
+                }
+            }
+
+            impl From<Degrees<$float_ty>> for Radians<$float_ty> {
+                fn from(Degrees(degrees): Degrees<$float_ty>) -> Radians<$float_ty> {
+                    let radians = degrees.to_radians();
+                    Radians(if radians > core::$float_ty::consts::PI {
+                        radians - core::$float_ty::consts::TAU
+                    } else {
+                        radians
+                    })
+                }
+            }
+
+            impl_hue_units!(@space Oklch, $float_ty);
+            impl_hue_units!(@space CieLCh, $float_ty);
+        )+
+    };
+    (@space $name:ident, $float_ty:ident) => {
+        impl From<$name<$float_ty>> for HueDegrees<$name<$float_ty>> {
+            fn from(col: $name<$float_ty>) -> HueDegrees<$name<$float_ty>> {
+                HueDegrees {
+                    color: $name {
+                        h: Degrees::from(Radians(col.h)).0,
+                        ..col
+                    },
+                }
+            }
+        }
+
+        impl From<HueDegrees<$name<$float_ty>>> for $name<$float_ty> {
+            fn from(col: HueDegrees<$name<$float_ty>>) -> $name<$float_ty> {
+                $name {
+                    h: Radians::from(Degrees(col.color.h)).0,
+                    ..col.color
+                }
+            }
+        }
+    };
+}
+
+impl_hue_units!(f32, f64);
+
+macro_rules! impl_hue_accessors {
+    ($($name:ident),+) => {
+        $(
+            impl<ComponentTy: Copy> $name<ComponentTy> {
+                /// The hue component of `self`, tagged with its unit.
+                pub fn hue(&self) -> Radians<ComponentTy> {
+                    Radians(self.h)
+                }
+            }
+
+            impl<ComponentTy: Copy> HueDegrees<$name<ComponentTy>> {
+                /// The hue component of `self`, tagged with its unit.
+                pub fn hue(&self) -> Degrees<ComponentTy> {
+                    Degrees(self.color.h)
+                }
+            }
+        )+
+    };
+}
+
+impl_hue_accessors!(Oklch, CieLCh);
@@ -0,0 +1,300 @@
+//! The chromaticities and tristimulus values of the CIE standard illuminants, as published in
+//! CIE 15:2004.
+//!
+//! These are provided as data only, so that calibration and conversion crates can share a single
+//! canonical source for them. Values are given for both the CIE 1931 2° and the CIE 1964 10°
+//! [`StandardObserver`]. Tristimulus values are normalized to `Y = 1` and rounded to six decimal
+//! places.
+//!
+//! Note that color space specifications often round their white point differently, i.e. sRGB uses
+//! x = 0.3127, y = 0.3290 for D65; [`WhitePoint::chromaticity`][crate::WhitePoint::chromaticity]
+//! gives the values used by the color spaces themselves.
+
+use crate::{Chromaticity, StandardObserver};
+
+/// The chromaticity and tristimulus values of an illuminant for one standard observer.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct IlluminantValues {
+    /// The CIE xy chromaticity coordinates.
+    pub xy: [f64; 2],
+    /// The CIE XYZ tristimulus values, normalized to `Y = 1`.
+    pub xyz: [f64; 3],
+}
+
+/// A CIE standard illuminant.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Illuminant {
+    /// The name of the illuminant, i.e. `"D65"`.
+    pub name: &'static str,
+    /// The values for the CIE 1931 2° standard observer.
+    pub two_degree: IlluminantValues,
+    /// The values for the CIE 1964 10° standard observer.
+    pub ten_degree: IlluminantValues,
+}
+
+impl Illuminant {
+    /// The values of this illuminant for `observer`.
+    pub const fn values(&self, observer: StandardObserver) -> IlluminantValues {
+        match observer {
+            StandardObserver::Cie1931TwoDegree => self.two_degree,
+            StandardObserver::Cie1964TenDegree => self.ten_degree,
+        }
+    }
+
+    /// The CIE 1931 2° chromaticity of this illuminant.
+    pub fn chromaticity(&self) -> Chromaticity {
+        Chromaticity::new(self.two_degree.xy[0] as f32, self.two_degree.xy[1] as f32)
+    }
+}
+
+/// CIE standard illuminant A, a tungsten-filament light with a correlated color temperature of about 2856 K.
+pub const A: Illuminant = Illuminant {
+    name: "A",
+    two_degree: IlluminantValues {
+        xy: [0.44757, 0.40745],
+        xyz: [1.098466, 1.0, 0.355823],
+    },
+    ten_degree: IlluminantValues {
+        xy: [0.45117, 0.40594],
+        xyz: [1.11142, 1.0, 0.351998],
+    },
+};
+
+/// CIE illuminant C, average daylight (obsolete; used by NTSC 1953).
+pub const C: Illuminant = Illuminant {
+    name: "C",
+    two_degree: IlluminantValues {
+        xy: [0.31006, 0.31616],
+        xyz: [0.980706, 1.0, 1.182249],
+    },
+    ten_degree: IlluminantValues {
+        xy: [0.31039, 0.31905],
+        xyz: [0.972857, 1.0, 1.161448],
+    },
+};
+
+/// CIE illuminant D50, horizon light. Used by ICC profile connection spaces.
+pub const D50: Illuminant = Illuminant {
+    name: "D50",
+    two_degree: IlluminantValues {
+        xy: [0.34567, 0.3585],
+        xyz: [0.964212, 1.0, 0.825188],
+    },
+    ten_degree: IlluminantValues {
+        xy: [0.34773, 0.35952],
+        xyz: [0.967206, 1.0, 0.81428],
+    },
+};
+
+/// CIE illuminant D55, mid-morning/mid-afternoon daylight.
+pub const D55: Illuminant = Illuminant {
+    name: "D55",
+    two_degree: IlluminantValues {
+        xy: [0.33242, 0.34743],
+        xyz: [0.956797, 1.0, 0.921481],
+    },
+    ten_degree: IlluminantValues {
+        xy: [0.33411, 0.34877],
+        xyz: [0.957967, 1.0, 0.909253],
+    },
+};
+
+/// CIE standard illuminant D65, noon daylight.
+pub const D65: Illuminant = Illuminant {
+    name: "D65",
+    two_degree: IlluminantValues {
+        xy: [0.31271, 0.32902],
+        xyz: [0.950429, 1.0, 1.0889],
+    },
+    ten_degree: IlluminantValues {
+        xy: [0.31382, 0.331],
+        xyz: [0.948097, 1.0, 1.073051],
+    },
+};
+
+/// CIE illuminant D75, north sky daylight.
+pub const D75: Illuminant = Illuminant {
+    name: "D75",
+    two_degree: IlluminantValues {
+        xy: [0.29902, 0.31485],
+        xyz: [0.949722, 1.0, 1.226394],
+    },
+    ten_degree: IlluminantValues {
+        xy: [0.29968, 0.3174],
+        xyz: [0.944171, 1.0, 1.206427],
+    },
+};
+
+/// The equal-energy illuminant E.
+pub const E: Illuminant = Illuminant {
+    name: "E",
+    two_degree: IlluminantValues {
+        xy: [1.0 / 3.0, 1.0 / 3.0],
+        xyz: [1.0, 1.0, 1.0],
+    },
+    ten_degree: IlluminantValues {
+        xy: [1.0 / 3.0, 1.0 / 3.0],
+        xyz: [1.0, 1.0, 1.0],
+    },
+};
+
+/// CIE fluorescent illuminant F1, a standard daylight fluorescent lamp.
+pub const F1: Illuminant = Illuminant {
+    name: "F1",
+    two_degree: IlluminantValues {
+        xy: [0.3131, 0.33727],
+        xyz: [0.928336, 1.0, 1.036647],
+    },
+    ten_degree: IlluminantValues {
+        xy: [0.31811, 0.33559],
+        xyz: [0.947913, 1.0, 1.031914],
+    },
+};
+
+/// CIE fluorescent illuminant F2, a standard cool white fluorescent lamp.
+pub const F2: Illuminant = Illuminant {
+    name: "F2",
+    two_degree: IlluminantValues {
+        xy: [0.37208, 0.37529],
+        xyz: [0.991447, 1.0, 0.673159],
+    },
+    ten_degree: IlluminantValues {
+        xy: [0.37925, 0.36733],
+        xyz: [1.03245, 1.0, 0.689897],
+    },
+};
+
+/// CIE fluorescent illuminant F3, a standard white fluorescent lamp.
+pub const F3: Illuminant = Illuminant {
+    name: "F3",
+    two_degree: IlluminantValues {
+        xy: [0.4091, 0.3943],
+        xyz: [1.037535, 1.0, 0.498605],
+    },
+    ten_degree: IlluminantValues {
+        xy: [0.41761, 0.38324],
+        xyz: [1.089683, 1.0, 0.519648],
+    },
+};
+
+/// CIE fluorescent illuminant F4, a standard warm white fluorescent lamp.
+pub const F4: Illuminant = Illuminant {
+    name: "F4",
+    two_degree: IlluminantValues {
+        xy: [0.44018, 0.40329],
+        xyz: [1.091473, 1.0, 0.388133],
+    },
+    ten_degree: IlluminantValues {
+        xy: [0.4492, 0.39074],
+        xyz: [1.149614, 1.0, 0.409633],
+    },
+};
+
+/// CIE fluorescent illuminant F5, a standard daylight fluorescent lamp.
+pub const F5: Illuminant = Illuminant {
+    name: "F5",
+    two_degree: IlluminantValues {
+        xy: [0.31379, 0.34531],
+        xyz: [0.90872, 1.0, 0.987229],
+    },
+    ten_degree: IlluminantValues {
+        xy: [0.31975, 0.34246],
+        xyz: [0.933686, 1.0, 0.986363],
+    },
+};
+
+/// CIE fluorescent illuminant F6, a standard light white fluorescent lamp.
+pub const F6: Illuminant = Illuminant {
+    name: "F6",
+    two_degree: IlluminantValues {
+        xy: [0.3779, 0.38835],
+        xyz: [0.973091, 1.0, 0.601905],
+    },
+    ten_degree: IlluminantValues {
+        xy: [0.3866, 0.37847],
+        xyz: [1.021481, 1.0, 0.620736],
+    },
+};
+
+/// CIE fluorescent illuminant F7, a broadband D65 simulator.
+pub const F7: Illuminant = Illuminant {
+    name: "F7",
+    two_degree: IlluminantValues {
+        xy: [0.31292, 0.32933],
+        xyz: [0.950172, 1.0, 1.086296],
+    },
+    ten_degree: IlluminantValues {
+        xy: [0.31569, 0.3296],
+        xyz: [0.957797, 1.0, 1.076183],
+    },
+};
+
+/// CIE fluorescent illuminant F8, a broadband D50 simulator.
+pub const F8: Illuminant = Illuminant {
+    name: "F8",
+    two_degree: IlluminantValues {
+        xy: [0.34588, 0.35875],
+        xyz: [0.964125, 1.0, 0.823331],
+    },
+    ten_degree: IlluminantValues {
+        xy: [0.34902, 0.35939],
+        xyz: [0.971146, 1.0, 0.811347],
+    },
+};
+
+/// CIE fluorescent illuminant F9, a broadband cool white deluxe fluorescent lamp.
+pub const F9: Illuminant = Illuminant {
+    name: "F9",
+    two_degree: IlluminantValues {
+        xy: [0.37417, 0.37281],
+        xyz: [1.003648, 1.0, 0.678684],
+    },
+    ten_degree: IlluminantValues {
+        xy: [0.37829, 0.37045],
+        xyz: [1.021163, 1.0, 0.678256],
+    },
+};
+
+/// CIE fluorescent illuminant F10, a narrowband (tri-band) 5000 K fluorescent lamp.
+pub const F10: Illuminant = Illuminant {
+    name: "F10",
+    two_degree: IlluminantValues {
+        xy: [0.34609, 0.35986],
+        xyz: [0.961735, 1.0, 0.817123],
+    },
+    ten_degree: IlluminantValues {
+        xy: [0.3509, 0.35444],
+        xyz: [0.990012, 1.0, 0.83134],
+    },
+};
+
+/// CIE fluorescent illuminant F11, a narrowband (tri-band) 4000 K fluorescent lamp.
+pub const F11: Illuminant = Illuminant {
+    name: "F11",
+    two_degree: IlluminantValues {
+        xy: [0.38052, 0.37713],
+        xyz: [1.008989, 1.0, 0.642617],
+    },
+    ten_degree: IlluminantValues {
+        xy: [0.38541, 0.37123],
+        xyz: [1.038197, 1.0, 0.65555],
+    },
+};
+
+/// CIE fluorescent illuminant F12, a narrowband (tri-band) 3000 K fluorescent lamp.
+pub const F12: Illuminant = Illuminant {
+    name: "F12",
+    two_degree: IlluminantValues {
+        xy: [0.43695, 0.40441],
+        xyz: [1.080463, 1.0, 0.392275],
+    },
+    ten_degree: IlluminantValues {
+        xy: [0.44256, 0.39717],
+        xyz: [1.114284, 1.0, 0.40353],
+    },
+};
+
+/// All of the illuminants in this module, in declaration order.
+pub const ALL: [Illuminant; 19] = [
+    A, C, D50, D55, D65, D75, E, F1, F2, F3, F4, F5, F6, F7, F8, F9, F10, F11, F12,
+];
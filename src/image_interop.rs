@@ -0,0 +1,179 @@
+use core::convert::TryFrom;
+
+use image::metadata::{
+    Cicp, CicpColorPrimaries as Primaries, CicpMatrixCoefficients,
+    CicpTransferCharacteristics as Transfer, CicpVideoFullRangeFlag,
+};
+use image::{ColorType, ExtendedColorType};
+
+use crate::{AlphaState, ColorDescriptor, Error, SignalRange, SpaceRef, Spaces};
+
+/// Follows the conventions of the `image` crate, where all color types hold encoded sRGB colors
+/// unless tagged otherwise, i.e. with [`ColorDescriptor::from_image_cicp`]. Fails with
+/// [`Error::UnsupportedFormat`] for unknown color types.
+impl TryFrom<ColorType> for ColorDescriptor {
+    type Error = Error;
+
+    fn try_from(color_type: ColorType) -> Result<Self, Error> {
+        let (space, alpha_state) = match color_type {
+            ColorType::L8 | ColorType::L16 => (Spaces::Luma, AlphaState::None),
+            ColorType::La8 | ColorType::La16 => (Spaces::Luma, AlphaState::Straight),
+            ColorType::Rgb8 | ColorType::Rgb16 | ColorType::Rgb32F => {
+                (Spaces::EncodedSrgb, AlphaState::None)
+            }
+            ColorType::Rgba8 | ColorType::Rgba16 | ColorType::Rgba32F => {
+                (Spaces::EncodedSrgb, AlphaState::Straight)
+            }
+            _ => return Err(Error::UnsupportedFormat),
+        };
+        Ok(ColorDescriptor {
+            alpha_state,
+            ..ColorDescriptor::new(space)
+        })
+    }
+}
+
+/// Follows the same conventions as the conversion from [`ColorType`]. Fails with
+/// [`Error::UnsupportedFormat`] for alpha-only, CMYK, and unknown color types.
+impl TryFrom<ExtendedColorType> for ColorDescriptor {
+    type Error = Error;
+
+    fn try_from(color_type: ExtendedColorType) -> Result<Self, Error> {
+        use ExtendedColorType as E;
+
+        let (space, alpha_state) = match color_type {
+            E::L1 | E::L2 | E::L4 | E::L8 | E::L16 => (Spaces::Luma, AlphaState::None),
+            E::La1 | E::La2 | E::La4 | E::La8 | E::La16 => (Spaces::Luma, AlphaState::Straight),
+            E::Rgb1 | E::Rgb2 | E::Rgb4 | E::Rgb5x1 | E::Rgb8 | E::Rgb16 | E::Bgr8 | E::Rgb32F => {
+                (Spaces::EncodedSrgb, AlphaState::None)
+            }
+            E::Rgba1 | E::Rgba2 | E::Rgba4 | E::Rgba8 | E::Rgba16 | E::Bgra8 | E::Rgba32F => {
+                (Spaces::EncodedSrgb, AlphaState::Straight)
+            }
+            _ => return Err(Error::UnsupportedFormat),
+        };
+        Ok(ColorDescriptor {
+            alpha_state,
+            ..ColorDescriptor::new(space)
+        })
+    }
+}
+
+/// Maps the RGB spaces which can be described with CICP code points, which requires identity
+/// matrix coefficients. Fails with [`Error::UnsupportedFormat`] for other combinations.
+impl TryFrom<Cicp> for Spaces {
+    type Error = Error;
+
+    fn try_from(cicp: Cicp) -> Result<Self, Error> {
+        if cicp.matrix != CicpMatrixCoefficients::Identity {
+            return Err(Error::UnsupportedFormat);
+        }
+        let space = match (cicp.primaries, cicp.transfer) {
+            (Primaries::SRgb, Transfer::SRgb) => Spaces::EncodedSrgb,
+            (Primaries::SRgb, Transfer::Linear) => Spaces::LinearSrgb,
+            (Primaries::SRgb, Transfer::Bt709 | Transfer::Bt601) => Spaces::EncodedRec709,
+            (Primaries::RgbB, Transfer::Linear) => Spaces::Bt601_625,
+            (Primaries::RgbB, Transfer::Bt709 | Transfer::Bt601) => Spaces::EncodedBt601_625,
+            (Primaries::Bt601, Transfer::Linear) => Spaces::Bt601_525,
+            (Primaries::Bt601, Transfer::Bt709 | Transfer::Bt601) => Spaces::EncodedBt601_525,
+            (Primaries::SmpteRp432, Transfer::Linear) => Spaces::DisplayP3,
+            (Primaries::SmpteRp432, Transfer::SRgb) => Spaces::EncodedDisplayP3,
+            (Primaries::SmpteRp432, Transfer::Smpte2084) => Spaces::EncodedDisplayP3PQ,
+            (Primaries::Rgb2020, Transfer::Linear) => Spaces::Bt2020,
+            (
+                Primaries::Rgb2020,
+                Transfer::Bt709 | Transfer::Bt601 | Transfer::Bt2020_10bit | Transfer::Bt2020_12bit,
+            ) => Spaces::EncodedBt2020,
+            (Primaries::Rgb2020, Transfer::Smpte2084) => Spaces::EncodedBt2100PQ,
+            (Primaries::Rgb2020, Transfer::Bt2100Hlg) => Spaces::EncodedBt2100HLG,
+            (Primaries::Xyz, Transfer::Linear) => Spaces::CieXYZ,
+            (Primaries::Xyz, Transfer::Smpte428) => Spaces::DciXYZPrime,
+            _ => return Err(Error::UnsupportedFormat),
+        };
+        Ok(space)
+    }
+}
+
+/// The inverse of the conversion from [`Cicp`], producing full-range code points. Fails with
+/// [`Error::UnsupportedFormat`] for spaces which can't be described with CICP code points.
+impl TryFrom<Spaces> for Cicp {
+    type Error = Error;
+
+    fn try_from(space: Spaces) -> Result<Self, Error> {
+        let (primaries, transfer) = match space {
+            Spaces::EncodedSrgb => (Primaries::SRgb, Transfer::SRgb),
+            Spaces::LinearSrgb => (Primaries::SRgb, Transfer::Linear),
+            Spaces::EncodedRec709 => (Primaries::SRgb, Transfer::Bt709),
+            Spaces::Bt601_625 => (Primaries::RgbB, Transfer::Linear),
+            Spaces::EncodedBt601_625 => (Primaries::RgbB, Transfer::Bt601),
+            Spaces::Bt601_525 => (Primaries::Bt601, Transfer::Linear),
+            Spaces::EncodedBt601_525 => (Primaries::Bt601, Transfer::Bt601),
+            Spaces::DisplayP3 => (Primaries::SmpteRp432, Transfer::Linear),
+            Spaces::EncodedDisplayP3 => (Primaries::SmpteRp432, Transfer::SRgb),
+            Spaces::EncodedDisplayP3PQ => (Primaries::SmpteRp432, Transfer::Smpte2084),
+            Spaces::Bt2020 => (Primaries::Rgb2020, Transfer::Linear),
+            Spaces::EncodedBt2020 => (Primaries::Rgb2020, Transfer::Bt2020_10bit),
+            Spaces::EncodedBt2100PQ => (Primaries::Rgb2020, Transfer::Smpte2084),
+            Spaces::EncodedBt2100HLG => (Primaries::Rgb2020, Transfer::Bt2100Hlg),
+            Spaces::CieXYZ => (Primaries::Xyz, Transfer::Linear),
+            Spaces::DciXYZPrime => (Primaries::Xyz, Transfer::Smpte428),
+            _ => return Err(Error::UnsupportedFormat),
+        };
+        Ok(Cicp {
+            primaries,
+            transfer,
+            matrix: CicpMatrixCoefficients::Identity,
+            full_range: CicpVideoFullRangeFlag::FullRange,
+        })
+    }
+}
+
+/// Fails with [`Error::UnsupportedFormat`] for custom spaces and spaces which can't be described
+/// with CICP code points.
+impl TryFrom<ColorDescriptor> for Cicp {
+    type Error = Error;
+
+    fn try_from(descriptor: ColorDescriptor) -> Result<Self, Error> {
+        let space = match descriptor.space {
+            SpaceRef::Builtin(space) => space,
+            SpaceRef::Custom(_) => return Err(Error::UnsupportedFormat),
+        };
+        let full_range = match descriptor.signal_range {
+            SignalRange::Full => CicpVideoFullRangeFlag::FullRange,
+            SignalRange::Limited => CicpVideoFullRangeFlag::NarrowRange,
+        };
+        Ok(Cicp {
+            full_range,
+            ..Cicp::try_from(space)?
+        })
+    }
+}
+
+impl ColorDescriptor {
+    /// Create a [`ColorDescriptor`] for an `image` buffer of the given `color_type`, tagged with
+    /// the color space described by `cicp`, i.e. from `DynamicImage::color_space`.
+    ///
+    /// Luminance color types are described as [`Spaces::Luminance`] if `cicp` has a linear
+    /// transfer function, and as [`Spaces::Luma`] otherwise. Fails with
+    /// [`Error::UnsupportedFormat`] if `color_type` is unknown or `cicp` can't be described with
+    /// one of the [`Spaces`].
+    pub fn from_image_cicp(color_type: ColorType, cicp: Cicp) -> Result<Self, Error> {
+        let base = ColorDescriptor::try_from(color_type)?;
+        let space = if color_type.has_color() {
+            Spaces::try_from(cicp)?
+        } else if cicp.transfer == Transfer::Linear {
+            Spaces::Luminance
+        } else {
+            Spaces::Luma
+        };
+        let signal_range = match cicp.full_range {
+            CicpVideoFullRangeFlag::NarrowRange => SignalRange::Limited,
+            _ => SignalRange::Full,
+        };
+        Ok(ColorDescriptor {
+            space: space.into(),
+            signal_range,
+            ..base
+        })
+    }
+}
@@ -0,0 +1,42 @@
+use core::iter::Map;
+
+use crate::{ExportCint, ImportCint};
+
+/// The iterator returned by [`CintIteratorExt::into_cint_iter`].
+pub type IntoCintIter<I> =
+    Map<I, fn(<I as Iterator>::Item) -> <<I as Iterator>::Item as ExportCint>::ExportTy>;
+
+/// The iterator returned by [`CintIteratorExt::into_provider_iter`].
+pub type IntoProviderIter<I, P> = Map<I, fn(<I as Iterator>::Item) -> P>;
+
+/// An extension trait for iterators which converts their items to or from `cint` types lazily, i.e.
+/// at the boundary of a streaming pipeline without collecting into an intermediate buffer.
+///
+/// This is implemented for all iterators.
+///
+/// ```rust,ignore
+/// let cint_colors = provider_colors.into_iter().into_cint_iter();
+/// // or
+/// let provider_colors = cint_colors.into_provider_iter::<ProviderColor>();
+/// ```
+pub trait CintIteratorExt: Iterator + Sized {
+    /// Convert each item into its canonical `cint` type, as given by [`ExportCint`] (and so
+    /// [`ColorInterop`][crate::ColorInterop]).
+    fn into_cint_iter(self) -> IntoCintIter<Self>
+    where
+        Self::Item: ExportCint,
+    {
+        self.map(ExportCint::export_cint)
+    }
+
+    /// Convert each `cint` item into the provider type `P`, as given by [`ImportCint`] (and so
+    /// [`ColorInterop`][crate::ColorInterop]).
+    fn into_provider_iter<P>(self) -> IntoProviderIter<Self, P>
+    where
+        P: ImportCint<ImportTy = Self::Item>,
+    {
+        self.map(P::import_cint)
+    }
+}
+
+impl<I: Iterator> CintIteratorExt for I {}
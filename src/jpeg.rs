@@ -0,0 +1,156 @@
+//! Mapping between JPEG color signaling and `cint` spaces and pixel formats.
+//!
+//! JPEG files don't record their color model directly. Instead, decoders infer it from the number of
+//! components, the presence of a JFIF (APP0) marker, the transform flag of an Adobe (APP14) marker,
+//! and the component identifiers of the frame header. [`JpegColorInfo::color_model`] applies the same
+//! rules as libjpeg so that decoders agree on the result.
+
+use crate::{
+    ChromaSubsampling, ColorDescriptor, ComponentType, Error, MatrixCoefficients, PixelFormat,
+    PlaneLayout, Spaces,
+};
+
+/// The values of the transform flag of an Adobe (APP14) marker.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum AdobeTransform {
+    /// The components are not transformed, i.e. RGB or CMYK.
+    Unknown = 0,
+    /// The components are Y'CbCr.
+    YCbCr = 1,
+    /// The components are Y'CbCr and K, i.e. transformed CMYK.
+    Ycck = 2,
+}
+
+impl AdobeTransform {
+    /// The transform for a value of the transform flag, or `None` if it is not a known value.
+    pub const fn from_value(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(Self::Unknown),
+            1 => Some(Self::YCbCr),
+            2 => Some(Self::Ycck),
+            _ => None,
+        }
+    }
+}
+
+/// The color model of the components of a JPEG image.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum JpegColorModel {
+    /// A single luma component.
+    Grayscale,
+    /// Y'CbCr components.
+    YCbCr,
+    /// Untransformed RGB components.
+    Rgb,
+    /// Untransformed CMYK components.
+    Cmyk,
+    /// Y'CbCr and K components, i.e. transformed CMYK.
+    Ycck,
+}
+
+/// The color signaling of a JPEG image.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct JpegColorInfo {
+    /// The number of components in the frame header.
+    pub num_components: u8,
+    /// The sample precision in the frame header, usually 8.
+    pub precision: u8,
+    /// Whether the image has a JFIF (APP0) marker.
+    pub has_jfif: bool,
+    /// The transform flag of the Adobe (APP14) marker, if the image has one.
+    pub adobe_transform: Option<AdobeTransform>,
+    /// The identifiers of the first (up to) four components in the frame header.
+    pub component_ids: [u8; 4],
+    /// The horizontal and vertical sampling factors of the first (up to) four components in the
+    /// frame header.
+    pub sampling_factors: [[u8; 2]; 4],
+}
+
+impl JpegColorInfo {
+    /// Create a new [`JpegColorInfo`] for an image with `num_components` components and no JFIF or
+    /// Adobe markers, with 8-bit precision, component identifiers counting up from 1, and no
+    /// subsampling.
+    pub fn new(num_components: u8) -> Self {
+        Self {
+            num_components,
+            precision: 8,
+            has_jfif: false,
+            adobe_transform: None,
+            component_ids: [1, 2, 3, 4],
+            sampling_factors: [[1, 1]; 4],
+        }
+    }
+
+    /// Infer the color model of the components, following the rules of libjpeg:
+    ///
+    /// * One component is always grayscale.
+    /// * Three components are Y'CbCr if there is a JFIF marker, as given by the transform flag if
+    ///   there is an Adobe marker, RGB if the component identifiers are `'R'`, `'G'`, and `'B'`, and
+    ///   Y'CbCr otherwise.
+    /// * Four components are YCCK if the Adobe transform flag says so, and CMYK otherwise.
+    ///
+    /// Fails with [`Error::UnsupportedFormat`] for any other number of components.
+    pub fn color_model(&self) -> Result<JpegColorModel, Error> {
+        match self.num_components {
+            1 => Ok(JpegColorModel::Grayscale),
+            3 if self.has_jfif => Ok(JpegColorModel::YCbCr),
+            3 => match self.adobe_transform {
+                Some(AdobeTransform::Unknown) => Ok(JpegColorModel::Rgb),
+                Some(_) => Ok(JpegColorModel::YCbCr),
+                None if self.component_ids[..3] == *b"RGB" => Ok(JpegColorModel::Rgb),
+                None => Ok(JpegColorModel::YCbCr),
+            },
+            4 => match self.adobe_transform {
+                Some(AdobeTransform::Ycck) => Ok(JpegColorModel::Ycck),
+                _ => Ok(JpegColorModel::Cmyk),
+            },
+            _ => Err(Error::UnsupportedFormat),
+        }
+    }
+
+    /// The space of the decoded components, i.e. [`Spaces::Luma`], [`Spaces::YPrimeCbCr`], or
+    /// [`Spaces::EncodedSrgb`], as JPEG images without an ICC profile are conventionally sRGB.
+    ///
+    /// Fails with [`Error::UnsupportedFormat`] for CMYK and YCCK images, which have no equivalent in
+    /// `cint`.
+    pub fn space(&self) -> Result<Spaces, Error> {
+        match self.color_model()? {
+            JpegColorModel::Grayscale => Ok(Spaces::Luma),
+            JpegColorModel::YCbCr => Ok(Spaces::YPrimeCbCr),
+            JpegColorModel::Rgb => Ok(Spaces::EncodedSrgb),
+            JpegColorModel::Cmyk | JpegColorModel::Ycck => Err(Error::UnsupportedFormat),
+        }
+    }
+
+    /// The [`PixelFormat`] of the decoded components, before any color conversion.
+    ///
+    /// Components are described as planar, since that is how they are coded. Y'CbCr images are
+    /// described as full range with the BT.601 matrix, as specified by JFIF, and with the chroma
+    /// subsampling given by the ratio between the sampling factors of the luma and chroma components.
+    ///
+    /// Fails with [`Error::UnsupportedFormat`] if [`JpegColorInfo::space`] does, or if the ratio of
+    /// sampling factors is not one of the [`ChromaSubsampling`] variants.
+    pub fn pixel_format(&self) -> Result<PixelFormat, Error> {
+        let space = self.space()?;
+        let mut format = PixelFormat::new(
+            ColorDescriptor::new(space),
+            ComponentType::Unsigned,
+            self.precision,
+        );
+        if self.num_components > 1 {
+            format.layout = PlaneLayout::Planar;
+        }
+        if space == Spaces::YPrimeCbCr {
+            let [[luma_h, luma_v], [chroma_h, chroma_v], ..] = self.sampling_factors;
+            if chroma_h == 0 || chroma_v == 0 || luma_h % chroma_h != 0 || luma_v % chroma_v != 0 {
+                return Err(Error::UnsupportedFormat);
+            }
+            format.chroma_subsampling =
+                ChromaSubsampling::from_factors(luma_h / chroma_h, luma_v / chroma_v)
+                    .ok_or(Error::UnsupportedFormat)?;
+            format.matrix_coefficients = Some(MatrixCoefficients::Bt601);
+        }
+        Ok(format)
+    }
+}
@@ -6,7 +6,15 @@
 //! It does not actually provide any conversion, math, etc. for these types, but rather
 //! serves as a stable interface that multiple libraries can rely on and then convert
 //! to their own internal representations to actually use. It is also `#![no_std]`.
-//! [`bytemuck`](https://docs.rs/bytemuck/) impls are provided with the `bytemuck` feature.
+//! [`bytemuck`](https://docs.rs/bytemuck/) impls are provided with the `bytemuck` feature, along with the
+//! `CastBytes` extension trait for casting colors to and from bytes and slices of components.
+//! With the `serde` feature, the color type of each space and its alpha forms are serialized as arrays
+//! of their components, and the `serde::hex` module can write 8-bit sRGB colors as `"#RRGGBB"` strings.
+//! With the `image` feature, `image::ColorType`, `image::ExtendedColorType`, and CICP color space
+//! tags from the `image` crate can be converted to and from [`ColorDescriptor`] and [`Spaces`].
+//! With the `exr` feature, OpenEXR channel layouts and chromaticities from the `exr` crate can be
+//! converted to [`ColorDescriptor`] and [`Spaces`], and `exr` samples to and from linear RGB colors.
+//! With the `std` feature, [`Error`] implements `std::error::Error`.
 //!
 //! # How to Use
 //!
@@ -23,13 +31,22 @@
 //! If you "linearized" or performed "inverse gamma correction" on such a color, then you instead
 //! might have a [`LinearSrgb<f32>`].
 //!
+//! The [`typedefs`] module provides short aliases for these commonly used types, like
+//! [`Srgba8`][typedefs::Srgba8] for an [`EncodedSrgb<u8>`] color with an alpha component.
+//!
 //! If you are more familiar with color encoding, then you'll find a collection of other color spaces
 //! represented, as well as the generic color types (like [`GenericColor3<ComponentTy>`]) which
 //! can be used if the color space you wish to use is not represented.
+//! Linear spaces encoded with a transfer function that has no dedicated type can be expressed with
+//! [`Encoded<ColorTy, TF>`][Encoded], i.e. `Encoded<DisplayP3<f32>, transfer::Gamma22>`, which also
+//! converts to and from the dedicated encoded types where they exist.
 //!
 //! All spaces are also collected into the [`Spaces`] enum, and you can get the variant represented
 //! by any of the concrete color types by taking advantage of the [`ColorType`]'s `SPACE` associated
 //! type, i.e. `EncodedSrgb::SPACE` will give `Spaces::EncodedSrgb`.
+//! The [`matrices`] module provides the standard RGB to CIE XYZ matrices for each RGB space as data.
+//! The [`illuminants`] module provides the chromaticities and tristimulus values of the CIE standard
+//! illuminants, for both standard observers.
 //!
 //! The [`ColorInterop`] trait exists to provide a "canonical" transformation to and from `cint` types.
 //! Since it is often possible to convert a color to and from multiple `cint` types, and because of
@@ -47,6 +64,16 @@
 //! types) should implement the relevant [`From`]/[`Into`] implementations to and from `cint` types, and
 //! also the [ColorInterop] trait once for each color type. The [`into_cint`][ColorInterop::into_cint] and
 //! [`from_cint`][ColorInterop::from_cint] methods will then be provided automatically.
+//! The [`CintCast`] extension trait additionally provides `color.cint_into::<Target>()` and
+//! `Target::cint_from(color)` for when the target type should be named explicitly.
+//! [`convert_via_cint`] converts directly between two types with the same canonical `cint` type.
+//! Provider types which can only be converted fallibly can implement [`TryColorInterop`] instead.
+//! Provider types whose canonical `cint` type differs between the two directions can implement
+//! [`ExportCint`] and [`ImportCint`] instead.
+//! Additional `cint` types which a provider type maps onto cleanly can be declared with [`ColorInteropAs`].
+//! Iterators of colors can be converted lazily with the [`CintIteratorExt`] extension trait.
+//! `cint`'s own color types implement [`ColorInterop`] reflexively, so generic code bounded on it
+//! also accepts them directly.
 //!
 //! ## Colors with alpha channels
 //!
@@ -55,12 +82,300 @@
 //! To represent an [`EncodedSrgb<u8>`] color with a premultiplied alpha component,
 //! you'd use [`PremultipliedAlpha<EncodedSrgb<u8>>`]. If, on the other hand, you want to represent
 //! an [`Oklab<f32>`] color with an independent alpha component, you'd use [`Alpha<Oklab<f32>>`]
+//!
+//! The alpha component uses the same type as the color components by default, but a different type
+//! can be given as a second type parameter, i.e. [`Alpha<EncodedSrgb<u8>, f32>`].
+//!
+//! If the alpha component comes before the color components in memory (i.e. ARGB rather than RGBA),
+//! use [`AlphaFirst<ColorTy>`] or [`PremultipliedAlphaFirst<ColorTy>`] instead.
+//!
+//! The alpha wrappers can be converted to and from arrays of components for any color type that
+//! implements [`ColorRepr`], including your own color types.
+//!
+//! ## Channel order
+//!
+//! RGB colors whose components are stored in blue, green, red order can be represented with
+//! [`Bgr<ColorTy>`], i.e. [`Alpha<Bgr<EncodedSrgb<u8>>>`] for a BGRA8 color.
+//!
+//! ## GPU and SIMD alignment
+//!
+//! [`Padded16<ColorTy>`] and [`Aligned16<ColorTy>`] wrap colors so that they are aligned to 16 bytes,
+//! matching the layout of `vec3` and `vec4` types in GPU buffers.
+//!
+//! ## SIMD component types
+//!
+//! Color types are generic over their component type, so SIMD vector types can be used as components to
+//! represent multiple colors at once in structure-of-arrays form, i.e. `LinearSrgb<f32x8>` holds eight
+//! [`LinearSrgb<f32>`] colors with each channel stored in its own vector. This works with any vector
+//! type, including `core::simd` types on nightly. With the `wide` feature, conversions to and from arrays
+//! of scalar colors are provided for the [`wide`](https://docs.rs/wide/) crate's `f32x4`, `f32x8`, `f64x2`
+//! and `f64x4` types, and since those types implement `bytemuck::Pod`, so do colors using them as components.
+//!
+//! Alternatively, a [`ColorBlock<ColorTy, N>`] stores `N` colors in structure-of-arrays form using arrays as
+//! components, i.e. `ColorBlock<LinearSrgb<f32>, 8>` is a `LinearSrgb<[f32; 8]>`.
+//!
+//! ## Fixed-point component types
+//!
+//! The fixed-point types from the [`fixed`](https://docs.rs/fixed/) crate can also be used as components,
+//! and implement `bytemuck::Pod` so colors using them do too. Unsigned types with one integer bit, like
+//! `U1F15`, make sense for RGB spaces whose components vary from 0 to 1, like [`LinearSrgb`] and [`EncodedSrgb`].
+//! Spaces with signed components, like the chroma components of [`Oklab`] or [`YPbPr`], need signed types
+//! like `I2F14`, and unbounded spaces like [`ScRgb`] or [`CieXYZ`] need more integer bits. With the `fixed`
+//! feature, the [`typedefs`] module provides aliases for some of these combinations.
+//!
+//! With the `half` feature, the half-precision `f16` type from the [`half`](https://docs.rs/half/) crate
+//! implements [`ColorComponent`] and [`FloatComponent`], for colors from formats like OpenEXR.
+//!
+//! ## Component-wise arithmetic
+//!
+//! `cint` intentionally does not provide color math. However, with the `arithmetic` feature, the generic
+//! color types ([`GenericColor1`] and [`GenericColor3`]) implement component-wise `Add`, `Sub`, and
+//! `Mul` by a scalar component, for use as plain carriers of channel data. These operations are **not**
+//! colorimetric.
+//!
+//! ## Generic component bounds
+//!
+//! Generic code can bound on the component type of any color, i.e.
+//! `fn f<C: ColorType>(c: C) where C::ComponentTy: num_traits::Float`. With the `num-traits` feature,
+//! the component types provided by `cint`, like [`U10`], also implement the relevant
+//! [`num-traits`](https://docs.rs/num-traits/) traits (`Bounded`, `ToPrimitive`, `FromPrimitive`
+//! and `NumCast`) so they can be used with such bounds as well.
+//!
+//! ## Limited range video
+//!
+//! Colors are assumed to use the full range of their component type. Video code values which use
+//! the limited (aka "studio") range can be marked as such by wrapping them in [`LimitedRange<ColorTy>`],
+//! i.e. [`LimitedRange<YPrimeCbCr<u8>>`]. The [`SignalRange`] enum can be used to track this at runtime.
+//!
+//! ## Relative spaces
+//!
+//! Relative spaces like [`Hsl`] and [`YPrimeCbCr`] are assumed to be derived from the RGB space
+//! documented on each of them. Colors derived from a different RGB space can be marked as such by
+//! wrapping them in [`DerivedFrom`], i.e. `DerivedFrom<Hsl<f32>, EncodedSrgb<f32>>`.
+//!
+//! ## Standard observers
+//!
+//! Tristimulus values are assumed to be computed with the CIE 1931 2° standard observer. Values
+//! computed with the CIE 1964 10° observer can be marked as such by wrapping them in [`Observed`],
+//! i.e. `Observed<CieXYZ<f32>, Cie1964TenDegree>`, so they aren't mixed up with 2° values. The
+//! [`StandardObserver`] enum can be used to track this at runtime.
+//!
+//! ## Space-generic colors
+//!
+//! Each space also has a zero-sized marker type in the [`space`] module, which can be used with the
+//! generic [`Color<S, T>`][Color] type to write code that covers all spaces at once. [`Color`] has the
+//! same layout as the corresponding concrete color type and converts to and from it with `From`.
+//!
+//! ## Runtime color descriptions
+//!
+//! A [`ColorDescriptor`] combines a color space, [`AlphaState`], and [`SignalRange`] to describe
+//! the colors in a buffer at runtime. Spaces which are not part of [`Spaces`] can be described with a
+//! [`CustomColorSpace`] and registered in a `ColorSpaceRegistry` (with the `alloc` feature), which
+//! hands out a [`CustomSpaceId`] to refer to them by.
+//! Legacy RGB spaces given as primaries with a pure gamma, i.e. by PNG `cHRM` and `gAMA` chunks, can
+//! be held in [`GammaRgb`] colors described by a [`GammaRgbParameters`].
+//! A [`PixelFormat`] further describes the memory format of the components, i.e. their numeric type,
+//! chroma subsampling, and plane layout. The [`tiff`] and [`jpeg`] modules map the color signaling of
+//! those file formats to pixel formats.
+//! With the `ffmpeg` feature, the `ffmpeg` module maps FFmpeg's pixel format and color metadata names.
+//! The [`v4l2`] module maps the pixel formats and color fields of V4L2 capture devices.
+//! The [`core_video`] module maps Apple CoreVideo pixel format types and CoreGraphics color space names.
+//! The [`android`] module maps Android `ADataSpace` and `ColorSpace.Named` constants.
+//! The [`web`] module maps the web's `PredefinedColorSpace` and WebGPU canvas configuration.
+//!
+//! The spaces a producer or consumer supports can be declared with a [`SpaceSet`], and [`negotiate`]
+//! picks the space to exchange colors in between the two.
+//! Per-space lookup tables can be built with a [`SpaceMap`], which is indexed by [`Spaces::index`].
 #![no_std]
 #![allow(unsafe_code)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+use core::marker::PhantomData;
+
 #[cfg(feature = "bytemuck")]
 use bytemuck::{Pod, Zeroable};
 
+/// Implements the standard traits, [`ColorType`], [`ColorInterop`], [`ColorRepr`], the array
+/// conversions, and the `bytemuck` traits for a `#[repr(transparent)]` wrapper around a color
+/// which carries a zero-sized `PhantomData<fn() -> $param>` marker alongside it, all by passing
+/// through to the contained color.
+///
+/// The `Debug` impl shows the marker as the given field, and the wrapper's space can be overridden
+/// with an expression in terms of `ColorTy` and the marker parameter.
+macro_rules! impl_transparent_wrapper {
+    (
+        $wrapper:ident<ColorTy, $param:ident $(: $bound:path)?> {
+            marker: $marker_field:ident,
+            debug: $debug_field:literal => $debug_value:expr,
+            $(space: $space:expr,)?
+        }
+    ) => {
+        impl<ColorTy: $crate::ColorType + Clone, $param $(: $bound)?> Clone for $wrapper<ColorTy, $param> {
+            fn clone(&self) -> Self {
+                Self {
+                    color: self.color.clone(),
+                    $marker_field: core::marker::PhantomData,
+                }
+            }
+        }
+
+        impl<ColorTy: $crate::ColorType + Copy, $param $(: $bound)?> Copy for $wrapper<ColorTy, $param> {}
+
+        impl<ColorTy: $crate::ColorType + core::fmt::Debug, $param $(: $bound)?> core::fmt::Debug for $wrapper<ColorTy, $param> {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.debug_struct(stringify!($wrapper))
+                    .field("color", &self.color)
+                    .field($debug_field, &$debug_value)
+                    .finish()
+            }
+        }
+
+        impl<ColorTy: $crate::ColorType + PartialEq, $param $(: $bound)?> PartialEq for $wrapper<ColorTy, $param> {
+            fn eq(&self, other: &Self) -> bool {
+                self.color == other.color
+            }
+        }
+
+        impl<ColorTy: $crate::ColorType + Eq, $param $(: $bound)?> Eq for $wrapper<ColorTy, $param> {}
+
+        impl<ColorTy: $crate::ColorType + PartialOrd, $param $(: $bound)?> PartialOrd for $wrapper<ColorTy, $param> {
+            fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+                self.color.partial_cmp(&other.color)
+            }
+        }
+
+        impl<ColorTy: $crate::ColorType + Ord, $param $(: $bound)?> Ord for $wrapper<ColorTy, $param> {
+            fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+                self.color.cmp(&other.color)
+            }
+        }
+
+        impl<ColorTy: $crate::ColorType + core::hash::Hash, $param $(: $bound)?> core::hash::Hash for $wrapper<ColorTy, $param> {
+            fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+                self.color.hash(state)
+            }
+        }
+
+        impl<ColorTy: $crate::ColorType, $param $(: $bound)?> $crate::ColorType for $wrapper<ColorTy, $param> {
+            type ComponentTy = ColorTy::ComponentTy;
+            const SPACE: $crate::Spaces = impl_transparent_wrapper!(@space ColorTy $(, $space)?);
+            const NUM_COMPONENTS: usize = ColorTy::NUM_COMPONENTS;
+        }
+
+        impl<ColorTy: $crate::ColorType, $param $(: $bound)?> $crate::ColorInterop for $wrapper<ColorTy, $param> {
+            type CintTy = Self;
+        }
+
+        // SAFETY: same layout is guaranteed by repr transparent
+        unsafe impl<ColorTy: $crate::ColorRepr, $param $(: $bound)?> $crate::ColorRepr for $wrapper<ColorTy, $param> {
+            type Repr = ColorTy::Repr;
+            type AlphaRepr = ColorTy::AlphaRepr;
+        }
+
+        impl<ColorTy: $crate::ColorType + AsRef<T>, $param $(: $bound)?, T: ?Sized> AsRef<T> for $wrapper<ColorTy, $param> {
+            fn as_ref(&self) -> &T {
+                self.color.as_ref()
+            }
+        }
+
+        impl<ColorTy: $crate::ColorType + AsMut<T>, $param $(: $bound)?, T: ?Sized> AsMut<T> for $wrapper<ColorTy, $param> {
+            fn as_mut(&mut self) -> &mut T {
+                self.color.as_mut()
+            }
+        }
+
+        #[cfg(feature = "bytemuck")]
+        unsafe impl<ColorTy: $crate::ColorType + bytemuck::Zeroable, $param $(: $bound)?> bytemuck::Zeroable for $wrapper<ColorTy, $param> {}
+        #[cfg(feature = "bytemuck")]
+        unsafe impl<ColorTy: $crate::ColorType + bytemuck::Pod, $param: 'static $(+ $bound)?> bytemuck::Pod for $wrapper<ColorTy, $param> {}
+    };
+    (@space $color_ty:ident) => {
+        $color_ty::SPACE
+    };
+    (@space $color_ty:ident, $space:expr) => {
+        $space
+    };
+}
+
+mod aligned;
+#[cfg(feature = "arithmetic")]
+mod arithmetic;
+mod block;
+#[cfg(feature = "bytemuck")]
+mod bytes;
+mod chromaticity;
+mod color;
+mod component;
+mod derived;
+mod descriptor;
+mod encoded;
+mod error;
+#[cfg(feature = "exr")]
+mod exr_interop;
+mod gamma_rgb;
+mod hdr;
+mod hue;
+#[cfg(feature = "image")]
+mod image_interop;
+mod iter;
+mod matrix_coefficients;
+mod observer;
+mod ordered;
+mod pixel_format;
+#[cfg(feature = "alloc")]
+mod registry;
+mod space_map;
+mod space_set;
+mod spaces;
+mod tagged;
+mod transfer_function;
+mod white_point;
+
+pub use aligned::*;
+pub use block::*;
+#[cfg(feature = "bytemuck")]
+pub use bytes::*;
+pub use chromaticity::*;
+pub use color::*;
+pub use component::*;
+pub use derived::*;
+pub use descriptor::*;
+pub use encoded::*;
+pub use error::Error;
+pub use gamma_rgb::*;
+pub use hdr::*;
+pub use hue::*;
+pub use iter::*;
+pub use matrix_coefficients::*;
+pub use observer::*;
+pub use ordered::*;
+pub use pixel_format::*;
+#[cfg(feature = "alloc")]
+pub use registry::*;
+pub use space_map::*;
+pub use space_set::*;
+pub use tagged::*;
+pub use transfer_function::*;
+pub use white_point::*;
+
+pub mod android;
+pub mod core_video;
+#[cfg(feature = "ffmpeg")]
+pub mod ffmpeg;
+pub mod illuminants;
+pub mod jpeg;
+pub mod matrices;
+#[cfg(feature = "serde")]
+pub mod serde;
+pub mod tiff;
+pub mod typedefs;
+pub mod v4l2;
+pub mod web;
+
 /// A trait used to simpify the interface of the [`Alpha`] and [`PremultipliedAlpha`] types and
 /// allow use with [`Spaces`] enum.
 pub trait ColorType {
@@ -69,6 +384,62 @@ pub trait ColorType {
     const NUM_COMPONENTS: usize;
 }
 
+/// A [`ColorType`] whose memory layout is an array of its components, which allows array
+/// conversions and `AsRef`/`AsMut` impls for the alpha wrappers to be provided generically.
+///
+/// This is implemented for all color types in the library, and can be implemented for
+/// user-defined color types to get the same conversions for i.e. `Alpha<MyColor>`.
+///
+/// # Safety
+///
+/// `Self` must have the same size and alignment as `Self::Repr`, which must be
+/// `[Self::ComponentTy; Self::NUM_COMPONENTS]`, and `Self::AlphaRepr` must be
+/// `[Self::ComponentTy; Self::NUM_COMPONENTS + 1]`.
+pub unsafe trait ColorRepr: ColorType + Copy {
+    /// The array form of `Self`, `[Self::ComponentTy; Self::NUM_COMPONENTS]`.
+    type Repr: Copy;
+    /// The array form of `Self` with an alpha component, `[Self::ComponentTy; Self::NUM_COMPONENTS + 1]`.
+    type AlphaRepr: Copy;
+}
+
+/// A color type which can be created from an array of `N` components, in memory order.
+///
+/// This is implemented for all [`ColorRepr`] types and their alpha wrappers, so generic code can
+/// accept any color with a given number of components, i.e. `C: FromComponents<3>`.
+pub trait FromComponents<const N: usize>: ColorType {
+    /// Create a color from its components, in memory order.
+    fn from_components(components: [Self::ComponentTy; N]) -> Self;
+}
+
+/// A color type which can be converted to an array of `N` components, in memory order.
+///
+/// This is implemented for all [`ColorRepr`] types and their alpha wrappers, so generic code can
+/// accept any color with a given number of components, i.e. `C: IntoComponents<3>`.
+pub trait IntoComponents<const N: usize>: ColorType {
+    /// Convert `self` to its components, in memory order.
+    fn into_components(self) -> [Self::ComponentTy; N];
+}
+
+impl<ColorTy, const N: usize> FromComponents<N> for ColorTy
+where
+    ColorTy: ColorRepr<Repr = [<ColorTy as ColorType>::ComponentTy; N]>,
+{
+    fn from_components(components: [ColorTy::ComponentTy; N]) -> Self {
+        // SAFETY: same layout is guaranteed by the `ColorRepr` contract
+        unsafe { core::mem::transmute_copy(&components) }
+    }
+}
+
+impl<ColorTy, const N: usize> IntoComponents<N> for ColorTy
+where
+    ColorTy: ColorRepr<Repr = [<ColorTy as ColorType>::ComponentTy; N]>,
+{
+    fn into_components(self) -> [ColorTy::ComponentTy; N] {
+        // SAFETY: same layout is guaranteed by the `ColorRepr` contract
+        unsafe { core::mem::transmute_copy(&self) }
+    }
+}
+
 /// A trait that should be implemented by provider crates on their local color types so that you can call
 /// `color.to_cint()` and `Color::from_cint(cint_color)`.
 ///
@@ -91,213 +462,1902 @@ where
     }
 }
 
+/// A trait that provider crates can implement for each of the `cint` types `C` that one of their color
+/// types maps onto cleanly, in addition to its single canonical [`ColorInterop`] type.
+///
+/// For example, a linear RGBA color type could implement [`ColorInterop`] with `Alpha<LinearSrgb<f32>>`
+/// as its canonical type, and also `ColorInteropAs<Alpha<GenericColor3<f32>>>`. The target can be named
+/// at the call site with [`CintCast::cint_as`].
+pub trait ColorInteropAs<C: ColorType>
+where
+    Self: Into<C>,
+    C: Into<Self>,
+{
+    /// Convert `self` into the `cint` type `C`.
+    fn into_cint_as(self) -> C {
+        self.into()
+    }
+
+    /// Create a `Self` from the `cint` type `C`.
+    fn from_cint_as(col: C) -> Self {
+        col.into()
+    }
+}
+
+/// The exporting half of [`ColorInterop`], for provider color types whose canonical `cint` type differs
+/// between the two directions, i.e. a type which naturally exports one space but can import several.
+///
+/// Such types implement [`ExportCint`] and [`ImportCint`] separately instead of [`ColorInterop`].
+/// Both are implemented for every type which implements [`ColorInterop`], so generic code bounded on
+/// them accepts both kinds of types.
+pub trait ExportCint
+where
+    Self: Into<<Self as ExportCint>::ExportTy>,
+{
+    type ExportTy;
+
+    /// Convert `self` into the canonical `cint` type it exports to.
+    fn export_cint(self) -> Self::ExportTy {
+        self.into()
+    }
+}
+
+/// The importing half of [`ColorInterop`]. See [`ExportCint`] for details.
+pub trait ImportCint: Sized {
+    type ImportTy: Into<Self>;
+
+    /// Create a `Self` from the canonical `cint` type it imports from.
+    fn import_cint(col: Self::ImportTy) -> Self {
+        col.into()
+    }
+}
+
+impl<T: ColorInterop> ExportCint for T {
+    type ExportTy = T::CintTy;
+
+    fn export_cint(self) -> Self::ExportTy {
+        self.into_cint()
+    }
+}
+
+impl<T: ColorInterop> ImportCint for T {
+    type ImportTy = T::CintTy;
+
+    fn import_cint(col: Self::ImportTy) -> Self {
+        T::from_cint(col)
+    }
+}
+
+/// A fallible version of [`ColorInterop`], for provider color types which can only be converted to
+/// or from their canonical `cint` type fallibly, i.e. because of out-of-range or palette-indexed colors.
+///
+/// This is implemented for every type which implements [`ColorInterop`], with conversions that never
+/// fail, so generic code bounded on [`TryColorInterop`] accepts both kinds of types.
+pub trait TryColorInterop: Sized {
+    type CintTy;
+
+    /// Try to create a `Self` from its canonical `cint` type.
+    fn try_from_cint(col: Self::CintTy) -> Result<Self, Error>;
+
+    /// Try to convert `self` into its canonical `cint` type.
+    fn try_into_cint(self) -> Result<Self::CintTy, Error>;
+}
+
+impl<T: ColorInterop> TryColorInterop for T {
+    type CintTy = T::CintTy;
+
+    fn try_from_cint(col: Self::CintTy) -> Result<Self, Error> {
+        Ok(T::from_cint(col))
+    }
+
+    fn try_into_cint(self) -> Result<Self::CintTy, Error> {
+        Ok(self.into_cint())
+    }
+}
+
+/// An extension trait providing conversions which route through the canonical `cint` type of a
+/// [`ColorInterop`] (or [`ExportCint`]) color, with the target given explicitly as a type parameter.
+///
+/// This is implemented for all types, and avoids the type inference issues of chaining
+/// `.into_cint().into()` when the target type can't be inferred:
+///
+/// ```rust,ignore
+/// let color_crate1 = color_crate2.cint_into::<ColorCrate1>();
+/// // or
+/// let color_crate1 = ColorCrate1::cint_from(color_crate2);
+/// ```
+pub trait CintCast: Sized {
+    /// Convert `self` into `Target` through the canonical `cint` type of `self`.
+    fn cint_into<Target>(self) -> Target
+    where
+        Self: ExportCint,
+        Target: From<<Self as ExportCint>::ExportTy>,
+    {
+        Target::from(self.export_cint())
+    }
+
+    /// Convert `self` into the `cint` type `C`, which is one of its [`ColorInteropAs`] targets.
+    fn cint_as<C>(self) -> C
+    where
+        C: ColorType + Into<Self>,
+        Self: ColorInteropAs<C>,
+    {
+        self.into_cint_as()
+    }
+
+    /// Create a `Self` from `source` through the canonical `cint` type of `source`.
+    fn cint_from<Source>(source: Source) -> Self
+    where
+        Source: ExportCint,
+        Self: From<<Source as ExportCint>::ExportTy>,
+    {
+        Self::from(source.export_cint())
+    }
+}
+
+impl<T> CintCast for T {}
+
+/// Convert a color from one provider crate's type to another's through their shared canonical `cint`
+/// type, without naming it.
+///
+/// This accepts any `A` whose exported `cint` type is imported by `B`, which includes all pairs of
+/// [`ColorInterop`] types with the same [`CintTy`][ColorInterop::CintTy].
+///
+/// ```rust,ignore
+/// let color_crate1: ColorCrate1 = cint::convert_via_cint(color_crate2);
+/// ```
+pub fn convert_via_cint<A, B>(a: A) -> B
+where
+    A: ExportCint,
+    B: ImportCint<ImportTy = A::ExportTy>,
+{
+    B::import_cint(a.export_cint())
+}
+
 /// A color with an alpha component.
 ///
-/// The color components and alpha component are completely separate.
-#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+/// The color components and alpha component are completely separate. The alpha component
+/// uses the same type as the color components unless `AlphaTy` is specified, i.e.
+/// `Alpha<EncodedSrgb<u8>, f32>` pairs 8-bit color components with a floating point alpha.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, PartialOrd, Eq, Ord)]
 #[repr(C)]
-pub struct Alpha<ColorTy: ColorType> {
+pub struct Alpha<ColorTy: ColorType, AlphaTy = <ColorTy as ColorType>::ComponentTy> {
     /// The contained color, which is completely separate from the `alpha` value.
     pub color: ColorTy,
     /// The alpha component.
-    pub alpha: ColorTy::ComponentTy,
+    pub alpha: AlphaTy,
 }
 
-impl<BaseColorTy: ColorType> ColorType for Alpha<BaseColorTy> {
+impl<BaseColorTy: ColorType, AlphaTy> ColorType for Alpha<BaseColorTy, AlphaTy> {
     type ComponentTy = BaseColorTy::ComponentTy;
     const SPACE: Spaces = BaseColorTy::SPACE;
     const NUM_COMPONENTS: usize = BaseColorTy::NUM_COMPONENTS + 1;
 }
 
 #[cfg(feature = "bytemuck")]
-unsafe impl<ColorTy: ColorType + Zeroable> Zeroable for Alpha<ColorTy> {}
+unsafe impl<ColorTy: ColorType + Zeroable, AlphaTy: Zeroable> Zeroable for Alpha<ColorTy, AlphaTy> {}
+// `ColorRepr` guarantees that `ColorTy` is laid out as an array of its components, so adding an
+// alpha component of the same type can't introduce padding. Other `Pod` color types, i.e. the
+// 16-byte aligned wrappers, may be over-aligned and would leave padding after the alpha component.
 #[cfg(feature = "bytemuck")]
-unsafe impl<ColorTy: ColorType + Pod> Pod for Alpha<ColorTy> {}
+unsafe impl<ColorTy: ColorRepr + Pod> Pod for Alpha<ColorTy> where ColorTy::ComponentTy: Pod {}
 
 /// A premultiplied color with an alpha component.
 ///
-/// The color components have been premultiplied by the alpha component.
-#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
-pub struct PremultipliedAlpha<ColorTy: ColorType> {
+/// The color components have been premultiplied by the alpha component. The alpha component
+/// uses the same type as the color components unless `AlphaTy` is specified.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, PartialOrd, Eq, Ord)]
+#[repr(C)]
+pub struct PremultipliedAlpha<ColorTy: ColorType, AlphaTy = <ColorTy as ColorType>::ComponentTy> {
     /// The contained color, which has been premultiplied with `alpha`
     pub color: ColorTy,
     /// The alpha component.
-    pub alpha: ColorTy::ComponentTy,
+    pub alpha: AlphaTy,
 }
 
-impl<BaseColorTy: ColorType> ColorType for PremultipliedAlpha<BaseColorTy> {
+impl<BaseColorTy: ColorType, AlphaTy> ColorType for PremultipliedAlpha<BaseColorTy, AlphaTy> {
     type ComponentTy = BaseColorTy::ComponentTy;
     const SPACE: Spaces = BaseColorTy::SPACE;
     const NUM_COMPONENTS: usize = BaseColorTy::NUM_COMPONENTS + 1;
 }
 
 #[cfg(feature = "bytemuck")]
-unsafe impl<ColorTy: ColorType + Zeroable> Zeroable for PremultipliedAlpha<ColorTy> {}
+unsafe impl<ColorTy: ColorType + Zeroable, AlphaTy: Zeroable> Zeroable
+    for PremultipliedAlpha<ColorTy, AlphaTy>
+{
+}
 #[cfg(feature = "bytemuck")]
-unsafe impl<ColorTy: ColorType + Pod> Pod for PremultipliedAlpha<ColorTy> {}
+unsafe impl<ColorTy: ColorRepr + Pod> Pod for PremultipliedAlpha<ColorTy> where
+    ColorTy::ComponentTy: Pod
+{
+}
 
-macro_rules! color_struct {
-    {
-        $(#[$doc:meta])*
-        $name:ident<$default_component_ty:ty, $num_components:literal> {
-            $($(#[$compdoc:meta])+
-            $compname:ident,)+
-        }
-    } => {
-        $(#[$doc])*
-        #[repr(C)]
-        #[derive(Clone, Copy, Debug, Hash, PartialEq, PartialOrd, Eq, Ord)]
-        pub struct $name<ComponentTy=$default_component_ty> {
-            $($(#[$compdoc])+
-            pub $compname: ComponentTy,)+
-        }
+/// A color with an alpha component which comes *before* the color components in memory,
+/// i.e. ARGB rather than RGBA ordering.
+///
+/// The color components and alpha component are completely separate. The alpha component
+/// uses the same type as the color components unless `AlphaTy` is specified. See [`Alpha`]
+/// for the more common alpha-last ordering.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, PartialOrd, Eq, Ord)]
+#[repr(C)]
+pub struct AlphaFirst<ColorTy: ColorType, AlphaTy = <ColorTy as ColorType>::ComponentTy> {
+    /// The alpha component.
+    pub alpha: AlphaTy,
+    /// The contained color, which is completely separate from the `alpha` value.
+    pub color: ColorTy,
+}
 
-        impl<CTy: Clone + Copy> ColorType for $name<CTy> {
-            type ComponentTy = CTy;
-            const SPACE: Spaces = Spaces::$name;
-            const NUM_COMPONENTS: usize = $num_components;
-        }
+impl<BaseColorTy: ColorType, AlphaTy> ColorType for AlphaFirst<BaseColorTy, AlphaTy> {
+    type ComponentTy = BaseColorTy::ComponentTy;
+    const SPACE: Spaces = BaseColorTy::SPACE;
+    const NUM_COMPONENTS: usize = BaseColorTy::NUM_COMPONENTS + 1;
+}
 
-        #[cfg(feature = "bytemuck")]
-        unsafe impl<ComponentTy: Zeroable> Zeroable for $name<ComponentTy> {}
-        #[cfg(feature = "bytemuck")]
-        unsafe impl<ComponentTy: Pod> Pod for $name<ComponentTy> {}
+#[cfg(feature = "bytemuck")]
+unsafe impl<ColorTy: ColorType + Zeroable, AlphaTy: Zeroable> Zeroable
+    for AlphaFirst<ColorTy, AlphaTy>
+{
+}
+#[cfg(feature = "bytemuck")]
+unsafe impl<ColorTy: ColorRepr + Pod> Pod for AlphaFirst<ColorTy> where ColorTy::ComponentTy: Pod {}
 
-        impl<ComponentTy> From<[ComponentTy; $num_components]> for $name<ComponentTy> {
-            fn from([$($compname),+]: [ComponentTy; $num_components]) -> $name<ComponentTy> {
-                $name {
-                    $($compname,)+
+/// A premultiplied color with an alpha component which comes *before* the color components
+/// in memory, i.e. ARGB rather than RGBA ordering.
+///
+/// The color components have been premultiplied by the alpha component. The alpha component
+/// uses the same type as the color components unless `AlphaTy` is specified. See
+/// [`PremultipliedAlpha`] for the more common alpha-last ordering.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, PartialOrd, Eq, Ord)]
+#[repr(C)]
+pub struct PremultipliedAlphaFirst<
+    ColorTy: ColorType,
+    AlphaTy = <ColorTy as ColorType>::ComponentTy,
+> {
+    /// The alpha component.
+    pub alpha: AlphaTy,
+    /// The contained color, which has been premultiplied with `alpha`
+    pub color: ColorTy,
+}
+
+impl<BaseColorTy: ColorType, AlphaTy> ColorType for PremultipliedAlphaFirst<BaseColorTy, AlphaTy> {
+    type ComponentTy = BaseColorTy::ComponentTy;
+    const SPACE: Spaces = BaseColorTy::SPACE;
+    const NUM_COMPONENTS: usize = BaseColorTy::NUM_COMPONENTS + 1;
+}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<ColorTy: ColorType + Zeroable, AlphaTy: Zeroable> Zeroable
+    for PremultipliedAlphaFirst<ColorTy, AlphaTy>
+{
+}
+#[cfg(feature = "bytemuck")]
+unsafe impl<ColorTy: ColorRepr + Pod> Pod for PremultipliedAlphaFirst<ColorTy> where
+    ColorTy::ComponentTy: Pod
+{
+}
+
+macro_rules! impl_alpha_wrapper {
+    ($($alphaty:ident),+) => {
+        $(
+            impl<ColorTy: ColorType, AlphaTy> $alphaty<ColorTy, AlphaTy> {
+                /// Create a new color from its color and alpha parts.
+                pub fn from_parts(color: ColorTy, alpha: AlphaTy) -> Self {
+                    Self { color, alpha }
+                }
+
+                /// Split `self` into its color and alpha parts.
+                pub fn into_parts(self) -> (ColorTy, AlphaTy) {
+                    (self.color, self.alpha)
+                }
+
+                /// Transform the contained color with `f`, preserving the alpha component.
+                pub fn map_color<NewColorTy, F>(self, f: F) -> $alphaty<NewColorTy, AlphaTy>
+                where
+                    NewColorTy: ColorType,
+                    F: FnOnce(ColorTy) -> NewColorTy,
+                {
+                    $alphaty {
+                        color: f(self.color),
+                        alpha: self.alpha,
+                    }
                 }
             }
-        }
 
-        #[allow(clippy::from_over_into)]
-        impl<ComponentTy> Into<[ComponentTy; $num_components]> for $name<ComponentTy> {
-            fn into(self) -> [ComponentTy; $num_components] {
-                let $name {
-                    $($compname,)+
-                } = self;
-                [$($compname),+]
+            impl<ColorTy: ColorType, AlphaTy> ColorInterop for $alphaty<ColorTy, AlphaTy> {
+                type CintTy = Self;
             }
-        }
 
-        impl<ComponentTy> AsRef<[ComponentTy; $num_components]> for $name<ComponentTy> {
-            fn as_ref(&self) -> &[ComponentTy; $num_components] {
-                // SAFETY: same layout is guaranteed by repr C
-                unsafe { &*(self as *const $name<ComponentTy> as *const [ComponentTy; $num_components]) }
+            impl<ColorTy: ColorType, AlphaTy> From<(ColorTy, AlphaTy)> for $alphaty<ColorTy, AlphaTy> {
+                fn from((color, alpha): (ColorTy, AlphaTy)) -> Self {
+                    Self { color, alpha }
+                }
             }
-        }
 
-        impl<ComponentTy> AsMut<[ComponentTy; $num_components]> for $name<ComponentTy> {
-            fn as_mut(&mut self) -> &mut [ComponentTy; $num_components] {
-                // SAFETY: same layout is guaranteed by repr C
-                unsafe { &mut *(self as *mut $name<ComponentTy> as *mut [ComponentTy; $num_components]) }
+            #[allow(clippy::from_over_into)]
+            impl<ColorTy: ColorType, AlphaTy> Into<(ColorTy, AlphaTy)> for $alphaty<ColorTy, AlphaTy> {
+                fn into(self) -> (ColorTy, AlphaTy) {
+                    self.into_parts()
+                }
             }
-        }
 
-        macro_rules! impl_alpha_traits {
-            ($alphaty:ident) => {
-                impl<ComponentTy: Copy> From<$alphaty<$name<ComponentTy>>> for $name<ComponentTy> {
-                    fn from(col_alpha: $alphaty<$name<ComponentTy>>) -> $name<ComponentTy> {
+            impl<ColorTy: ColorType> $alphaty<ColorTy>
+            where
+                ColorTy::ComponentTy: ColorComponent,
+            {
+                /// Create a new color with a fully opaque alpha component, i.e. an alpha of
+                /// [`ColorComponent::ONE`].
+                pub fn opaque(color: ColorTy) -> Self {
+                    Self {
+                        color,
+                        alpha: ColorTy::ComponentTy::ONE,
+                    }
+                }
+            }
+
+            impl<ColorTy: ColorRepr> $alphaty<ColorTy> {
+                /// Create a new color from its array form, including the alpha component.
+                pub fn from_repr(repr: ColorTy::AlphaRepr) -> Self {
+                    // SAFETY: same layout is guaranteed by repr C and the `ColorRepr` contract
+                    unsafe { core::mem::transmute_copy(&repr) }
+                }
+
+                /// Convert `self` to its array form, including the alpha component.
+                pub fn into_repr(self) -> ColorTy::AlphaRepr {
+                    // SAFETY: same layout is guaranteed by repr C and the `ColorRepr` contract
+                    unsafe { core::mem::transmute_copy(&self) }
+                }
+            }
+
+            impl<ColorTy, const N: usize> FromComponents<N> for $alphaty<ColorTy>
+            where
+                ColorTy: ColorRepr<AlphaRepr = [<ColorTy as ColorType>::ComponentTy; N]>,
+            {
+                fn from_components(components: [ColorTy::ComponentTy; N]) -> Self {
+                    Self::from_repr(components)
+                }
+            }
+
+            impl<ColorTy, const N: usize> IntoComponents<N> for $alphaty<ColorTy>
+            where
+                ColorTy: ColorRepr<AlphaRepr = [<ColorTy as ColorType>::ComponentTy; N]>,
+            {
+                fn into_components(self) -> [ColorTy::ComponentTy; N] {
+                    self.into_repr()
+                }
+            }
+
+            impl<ColorTy: ColorRepr> AsRef<ColorTy::AlphaRepr> for $alphaty<ColorTy> {
+                fn as_ref(&self) -> &ColorTy::AlphaRepr {
+                    // SAFETY: same layout is guaranteed by repr C and the `ColorRepr` contract
+                    unsafe { &*(self as *const $alphaty<ColorTy> as *const ColorTy::AlphaRepr) }
+                }
+            }
+
+            impl<ColorTy: ColorRepr> AsMut<ColorTy::AlphaRepr> for $alphaty<ColorTy> {
+                fn as_mut(&mut self) -> &mut ColorTy::AlphaRepr {
+                    // SAFETY: same layout is guaranteed by repr C and the `ColorRepr` contract
+                    unsafe { &mut *(self as *mut $alphaty<ColorTy> as *mut ColorTy::AlphaRepr) }
+                }
+            }
+
+            impl<ColorTy: ColorType, AlphaTy: ColorComponent> From<ColorTy> for $alphaty<ColorTy, AlphaTy> {
+                fn from(color: ColorTy) -> Self {
+                    Self {
+                        color,
+                        alpha: AlphaTy::ONE,
+                    }
+                }
+            }
+        )+
+    };
+}
+
+impl_alpha_wrapper!(
+    Alpha,
+    PremultipliedAlpha,
+    AlphaFirst,
+    PremultipliedAlphaFirst
+);
+
+/// Whether, and how, a color has an alpha component. Useful for tracking alpha semantics
+/// at runtime, alongside [`Spaces`].
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum AlphaState {
+    /// There is no alpha component.
+    None,
+    /// There is a separate alpha component, as in [`Alpha`] and [`AlphaFirst`].
+    Straight,
+    /// There is an alpha component which the color components have been premultiplied by,
+    /// as in [`PremultipliedAlpha`] and [`PremultipliedAlphaFirst`].
+    Premultiplied,
+}
+
+macro_rules! impl_alpha_state {
+    ($($straightty:ident <=> $premulty:ident),+) => {
+        $(
+            impl<ColorTy: ColorType, AlphaTy> $straightty<ColorTy, AlphaTy> {
+                /// The [`AlphaState`] of this color.
+                pub const ALPHA_STATE: AlphaState = AlphaState::Straight;
+
+                /// Reinterpret `self` as already being premultiplied by its alpha component.
+                ///
+                /// This only changes the type, it does *not* perform any premultiplication math, and
+                /// so is only correct if the color components have already been premultiplied.
+                pub fn assert_premultiplied(self) -> $premulty<ColorTy, AlphaTy> {
+                    $premulty {
+                        color: self.color,
+                        alpha: self.alpha,
+                    }
+                }
+            }
+
+            impl<ColorTy: ColorType, AlphaTy> $premulty<ColorTy, AlphaTy> {
+                /// The [`AlphaState`] of this color.
+                pub const ALPHA_STATE: AlphaState = AlphaState::Premultiplied;
+
+                /// Reinterpret `self` as having a separate, non-premultiplied alpha component.
+                ///
+                /// This only changes the type, it does *not* perform any math to undo premultiplication,
+                /// and so is only correct if the color components were not actually premultiplied.
+                pub fn assert_straight(self) -> $straightty<ColorTy, AlphaTy> {
+                    $straightty {
+                        color: self.color,
+                        alpha: self.alpha,
+                    }
+                }
+            }
+        )+
+    };
+}
+
+impl_alpha_state!(Alpha <=> PremultipliedAlpha, AlphaFirst <=> PremultipliedAlphaFirst);
+
+/// An extension trait implemented for all [`ColorType`]s which allows fluently composing a color
+/// with an alpha component, i.e. `color.with_alpha(0.5)`.
+pub trait WithAlpha: ColorType + Sized {
+    /// Create an [`Alpha`] color from `self` and a separate `alpha` component.
+    fn with_alpha(self, alpha: Self::ComponentTy) -> Alpha<Self> {
+        Alpha { color: self, alpha }
+    }
+
+    /// Create a [`PremultipliedAlpha`] color from `self`, which must already be premultiplied by `alpha`,
+    /// and an `alpha` component.
+    fn with_premultiplied_alpha(self, alpha: Self::ComponentTy) -> PremultipliedAlpha<Self> {
+        PremultipliedAlpha { color: self, alpha }
+    }
+}
+
+impl<ColorTy: ColorType> WithAlpha for ColorTy {}
+
+/// An RGB color whose components are stored in blue, green, red order.
+///
+/// `ColorTy` should be a 3-component RGB color type, i.e. [`EncodedSrgb`], which defines the color
+/// space of the contained components. This allows BGR and BGRA (via `Alpha<Bgr<ColorTy>>`) buffers
+/// to be reinterpreted as `cint` types without reordering components.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, PartialOrd, Eq, Ord)]
+#[repr(C)]
+pub struct Bgr<ColorTy: ColorType> {
+    /// The blue component.
+    pub b: ColorTy::ComponentTy,
+    /// The green component.
+    pub g: ColorTy::ComponentTy,
+    /// The red component.
+    pub r: ColorTy::ComponentTy,
+    _color: PhantomData<ColorTy>,
+}
+
+impl<ColorTy: ColorType> Bgr<ColorTy> {
+    /// Create a new [`Bgr`] color from its components.
+    pub const fn new(
+        b: ColorTy::ComponentTy,
+        g: ColorTy::ComponentTy,
+        r: ColorTy::ComponentTy,
+    ) -> Self {
+        Self {
+            b,
+            g,
+            r,
+            _color: PhantomData,
+        }
+    }
+}
+
+impl<ColorTy> Bgr<ColorTy>
+where
+    ColorTy: ColorType + From<[ColorTy::ComponentTy; 3]> + Into<[ColorTy::ComponentTy; 3]>,
+{
+    /// Convert an RGB-ordered color into a [`Bgr`] color.
+    pub fn from_rgb(color: ColorTy) -> Self {
+        let [r, g, b] = color.into();
+        Self::new(b, g, r)
+    }
+
+    /// Convert `self` into its RGB-ordered color type.
+    pub fn into_rgb(self) -> ColorTy {
+        ColorTy::from([self.r, self.g, self.b])
+    }
+}
+
+impl<ColorTy> From<ColorTy> for Bgr<ColorTy>
+where
+    ColorTy: ColorType + From<[ColorTy::ComponentTy; 3]> + Into<[ColorTy::ComponentTy; 3]>,
+{
+    fn from(color: ColorTy) -> Self {
+        Self::from_rgb(color)
+    }
+}
+
+impl<ColorTy: ColorType> ColorType for Bgr<ColorTy> {
+    type ComponentTy = ColorTy::ComponentTy;
+    const SPACE: Spaces = ColorTy::SPACE;
+    const NUM_COMPONENTS: usize = 3;
+}
+
+impl<ColorTy: ColorType> ColorInterop for Bgr<ColorTy> {
+    type CintTy = Self;
+}
+
+impl<ColorTy: ColorType> From<[ColorTy::ComponentTy; 3]> for Bgr<ColorTy> {
+    fn from([b, g, r]: [ColorTy::ComponentTy; 3]) -> Self {
+        Self::new(b, g, r)
+    }
+}
+
+#[allow(clippy::from_over_into)]
+impl<ColorTy: ColorType> Into<[ColorTy::ComponentTy; 3]> for Bgr<ColorTy> {
+    fn into(self) -> [ColorTy::ComponentTy; 3] {
+        [self.b, self.g, self.r]
+    }
+}
+
+impl<ColorTy: ColorType> AsRef<[ColorTy::ComponentTy; 3]> for Bgr<ColorTy> {
+    fn as_ref(&self) -> &[ColorTy::ComponentTy; 3] {
+        // SAFETY: same layout is guaranteed by repr C
+        unsafe { &*(self as *const Bgr<ColorTy> as *const [ColorTy::ComponentTy; 3]) }
+    }
+}
+
+impl<ColorTy: ColorType> AsMut<[ColorTy::ComponentTy; 3]> for Bgr<ColorTy> {
+    fn as_mut(&mut self) -> &mut [ColorTy::ComponentTy; 3] {
+        // SAFETY: same layout is guaranteed by repr C
+        unsafe { &mut *(self as *mut Bgr<ColorTy> as *mut [ColorTy::ComponentTy; 3]) }
+    }
+}
+
+// SAFETY: same layout is guaranteed by repr C
+unsafe impl<ColorTy: ColorType + Copy> ColorRepr for Bgr<ColorTy> {
+    type Repr = [ColorTy::ComponentTy; 3];
+    type AlphaRepr = [ColorTy::ComponentTy; 4];
+}
+
+// The components are stored directly, so the bounds must be on the component type rather than
+// on `ColorTy` itself.
+#[cfg(feature = "bytemuck")]
+unsafe impl<ColorTy: ColorType> Zeroable for Bgr<ColorTy> where ColorTy::ComponentTy: Zeroable {}
+#[cfg(feature = "bytemuck")]
+unsafe impl<ColorTy: ColorType + Copy + 'static> Pod for Bgr<ColorTy> where ColorTy::ComponentTy: Pod
+{}
+
+/// The range of code values used by an encoded video signal.
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum SignalRange {
+    /// The full range of code values is used, i.e. 0-255 for 8-bit or 0-1023 for 10-bit values.
+    Full,
+    /// The limited (aka "video", "studio", or "narrow") range of code values is used,
+    /// i.e. 16-235 (16-240 for chroma) for 8-bit or 64-940 (64-960 for chroma) for 10-bit values.
+    Limited,
+}
+
+/// A color whose components hold limited-range (aka "video" or "studio" range) code values.
+///
+/// See [`SignalRange::Limited`]. Colors that are not wrapped in [`LimitedRange`] are assumed
+/// to use the full range of code values.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, PartialOrd, Eq, Ord)]
+#[repr(transparent)]
+pub struct LimitedRange<ColorTy: ColorType> {
+    /// The contained color, whose components are limited-range code values.
+    pub color: ColorTy,
+}
+
+impl<BaseColorTy: ColorType> ColorType for LimitedRange<BaseColorTy> {
+    type ComponentTy = BaseColorTy::ComponentTy;
+    const SPACE: Spaces = BaseColorTy::SPACE;
+    const NUM_COMPONENTS: usize = BaseColorTy::NUM_COMPONENTS;
+}
+
+impl<ColorTy: ColorType> ColorInterop for LimitedRange<ColorTy> {
+    type CintTy = Self;
+}
+
+impl<ColorTy: ColorType> LimitedRange<ColorTy> {
+    /// The [`SignalRange`] of the contained color.
+    pub const SIGNAL_RANGE: SignalRange = SignalRange::Limited;
+}
+
+impl<ColorTy: ColorType + AsRef<T>, T: ?Sized> AsRef<T> for LimitedRange<ColorTy> {
+    fn as_ref(&self) -> &T {
+        self.color.as_ref()
+    }
+}
+
+impl<ColorTy: ColorType + AsMut<T>, T: ?Sized> AsMut<T> for LimitedRange<ColorTy> {
+    fn as_mut(&mut self) -> &mut T {
+        self.color.as_mut()
+    }
+}
+
+// SAFETY: same layout is guaranteed by repr transparent
+unsafe impl<ColorTy: ColorRepr> ColorRepr for LimitedRange<ColorTy> {
+    type Repr = ColorTy::Repr;
+    type AlphaRepr = ColorTy::AlphaRepr;
+}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<ColorTy: ColorType + Zeroable> Zeroable for LimitedRange<ColorTy> {}
+#[cfg(feature = "bytemuck")]
+unsafe impl<ColorTy: ColorType + Pod> Pod for LimitedRange<ColorTy> {}
+
+/// Asserts at compile time that a color type has the same size and alignment as its array form,
+/// which the `AsRef`/`AsMut` array impls and `bytemuck` impls rely on.
+/// Colors given with `align(N)` are over-aligned, i.e. the 16-byte aligned wrappers, and are only
+/// checked to have no padding beyond their components and to have the given alignment.
+macro_rules! assert_array_layout {
+    ($($color_ty:ty => [$component_ty:ty; $num_components:expr] $(align($align:expr))?),+ $(,)?) => {
+        $(
+            assert_array_layout!(@assert $color_ty => [$component_ty; $num_components] $(align($align))?);
+        )+
+    };
+    (@assert $color_ty:ty => [$component_ty:ty; $num_components:expr]) => {
+        const _: () = assert!(
+            core::mem::size_of::<$color_ty>() == core::mem::size_of::<[$component_ty; $num_components]>()
+                && core::mem::align_of::<$color_ty>() == core::mem::align_of::<[$component_ty; $num_components]>()
+        );
+    };
+    (@assert $color_ty:ty => [$component_ty:ty; $num_components:expr] align($align:expr)) => {
+        const _: () = assert!(
+            core::mem::size_of::<$color_ty>() == core::mem::size_of::<[$component_ty; $num_components]>()
+                && core::mem::align_of::<$color_ty>() == $align
+        );
+    };
+}
+
+assert_array_layout! {
+    Bgr<EncodedSrgb<u8>> => [u8; 3],
+    Bgr<LinearSrgb<f32>> => [f32; 3],
+    LimitedRange<YPrimeCbCr<u8>> => [u8; 3],
+    LimitedRange<Alpha<YPrimeCbCr<u8>>> => [u8; 4],
+    Observed<CieXYZ<f32>, Cie1964TenDegree> => [f32; 3],
+    DerivedFrom<Hsl<f32>, EncodedSrgb<f32>> => [f32; 3],
+    HueDegrees<Oklch<f32>> => [f32; 3],
+    Alpha<Bgr<EncodedSrgb<u8>>> => [u8; 4],
+}
+
+/// Implements the comparison and hashing traits for an [`OrderedColor`] wrapping a color type whose
+/// components can be viewed as an array.
+macro_rules! impl_ordered_color {
+    ($color_ty:ty) => {
+        impl<ComponentTy: TotalOrdComponent> PartialEq for OrderedColor<$color_ty> {
+            fn eq(&self, other: &Self) -> bool {
+                self.cmp(other) == core::cmp::Ordering::Equal
+            }
+        }
+
+        impl<ComponentTy: TotalOrdComponent> Eq for OrderedColor<$color_ty> {}
+
+        impl<ComponentTy: TotalOrdComponent> PartialOrd for OrderedColor<$color_ty> {
+            fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl<ComponentTy: TotalOrdComponent> Ord for OrderedColor<$color_ty> {
+            fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+                ordered::total_cmp_components(self.0.as_ref(), other.0.as_ref())
+            }
+        }
+
+        impl<ComponentTy: TotalOrdComponent> core::hash::Hash for OrderedColor<$color_ty> {
+            fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+                ordered::hash_components(self.0.as_ref(), state)
+            }
+        }
+    };
+}
+
+/// Implements `Pod` for the 16-byte aligned wrappers of 3-component colors with 32-bit components,
+/// which are the only cases guaranteed not to contain implicit padding.
+#[cfg(feature = "bytemuck")]
+macro_rules! impl_aligned_pod {
+    (@impl $name:ident, $($component_ty:ty),+) => {
+        $(
+            unsafe impl Pod for Padded16<$name<$component_ty>> {}
+            unsafe impl Pod for Aligned16<Alpha<$name<$component_ty>>> {}
+            unsafe impl Pod for Aligned16<PremultipliedAlpha<$name<$component_ty>>> {}
+            unsafe impl Pod for Aligned16<AlphaFirst<$name<$component_ty>>> {}
+            unsafe impl Pod for Aligned16<PremultipliedAlphaFirst<$name<$component_ty>>> {}
+
+            assert_array_layout! {
+                Padded16<$name<$component_ty>> => [$component_ty; 4] align(16),
+                Aligned16<Alpha<$name<$component_ty>>> => [$component_ty; 4] align(16),
+                Aligned16<PremultipliedAlpha<$name<$component_ty>>> => [$component_ty; 4] align(16),
+                Aligned16<AlphaFirst<$name<$component_ty>>> => [$component_ty; 4] align(16),
+                Aligned16<PremultipliedAlphaFirst<$name<$component_ty>>> => [$component_ty; 4] align(16),
+            }
+        )+
+    };
+    (3, $name:ident) => {
+        impl_aligned_pod!(@impl $name, f32, u32, i32);
+    };
+    ($num_components:tt, $name:ident) => {};
+}
+
+macro_rules! color_struct {
+    {
+        $(#[$doc:meta])*
+        $name:ident<$default_component_ty:ty, $num_components:tt> {
+            $($(#[$compdoc:meta])+
+            $compname:ident,)+
+        }
+    } => {
+        $(#[$doc])*
+        #[repr(C)]
+        #[derive(Clone, Copy, Debug, Hash, PartialEq, PartialOrd, Eq, Ord)]
+        pub struct $name<ComponentTy=$default_component_ty> {
+            $($(#[$compdoc])+
+            pub $compname: ComponentTy,)+
+        }
+
+        impl<CTy: Clone + Copy> ColorType for $name<CTy> {
+            type ComponentTy = CTy;
+            const SPACE: Spaces = Spaces::$name;
+            const NUM_COMPONENTS: usize = $num_components;
+        }
+
+        impl<ComponentTy> ColorInterop for $name<ComponentTy> {
+            type CintTy = Self;
+        }
+
+        #[cfg(feature = "bytemuck")]
+        unsafe impl<ComponentTy: Zeroable> Zeroable for $name<ComponentTy> {}
+        #[cfg(feature = "bytemuck")]
+        unsafe impl<ComponentTy: Pod> Pod for $name<ComponentTy> {}
+
+        impl<ComponentTy> From<[ComponentTy; $num_components]> for $name<ComponentTy> {
+            fn from([$($compname),+]: [ComponentTy; $num_components]) -> $name<ComponentTy> {
+                $name {
+                    $($compname,)+
+                }
+            }
+        }
+
+        #[allow(clippy::from_over_into)]
+        impl<ComponentTy> Into<[ComponentTy; $num_components]> for $name<ComponentTy> {
+            fn into(self) -> [ComponentTy; $num_components] {
+                let $name {
+                    $($compname,)+
+                } = self;
+                [$($compname),+]
+            }
+        }
+
+        impl_ordered_color!($name<ComponentTy>);
+
+        #[cfg(feature = "serde")]
+        impl<ComponentTy: ::serde::Serialize> ::serde::Serialize for $name<ComponentTy> {
+            fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                use ::serde::ser::SerializeTuple;
+
+                let mut tuple = serializer.serialize_tuple($num_components)?;
+                $(tuple.serialize_element(&self.$compname)?;)+
+                tuple.end()
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de, ComponentTy: ::serde::Deserialize<'de>> ::serde::Deserialize<'de> for $name<ComponentTy> {
+            fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                <[ComponentTy; $num_components] as ::serde::Deserialize<'de>>::deserialize(deserializer).map($name::from)
+            }
+        }
+
+        assert_array_layout! {
+            $name<u8> => [u8; $num_components],
+            $name<u16> => [u16; $num_components],
+            $name<f32> => [f32; $num_components],
+            $name<f64> => [f64; $num_components],
+        }
+
+        impl<ComponentTy: Copy> core::convert::TryFrom<&[ComponentTy]> for $name<ComponentTy> {
+            type Error = Error;
+
+            fn try_from(components: &[ComponentTy]) -> Result<$name<ComponentTy>, Error> {
+                match *components {
+                    [$($compname),+] => Ok($name { $($compname,)+ }),
+                    _ => Err(Error::WrongComponentCount {
+                        expected: $num_components,
+                        found: components.len(),
+                    }),
+                }
+            }
+        }
+
+        // SAFETY: same layout is guaranteed by repr C
+        unsafe impl<ComponentTy: Copy> ColorRepr for $name<ComponentTy> {
+            type Repr = [ComponentTy; $num_components];
+            type AlphaRepr = [ComponentTy; $num_components + 1];
+        }
+
+        impl<ComponentTy> AsRef<[ComponentTy; $num_components]> for $name<ComponentTy> {
+            fn as_ref(&self) -> &[ComponentTy; $num_components] {
+                // SAFETY: same layout is guaranteed by repr C
+                unsafe { &*(self as *const $name<ComponentTy> as *const [ComponentTy; $num_components]) }
+            }
+        }
+
+        impl<ComponentTy> AsMut<[ComponentTy; $num_components]> for $name<ComponentTy> {
+            fn as_mut(&mut self) -> &mut [ComponentTy; $num_components] {
+                // SAFETY: same layout is guaranteed by repr C
+                unsafe { &mut *(self as *mut $name<ComponentTy> as *mut [ComponentTy; $num_components]) }
+            }
+        }
+
+        impl<ComponentTy> $name<ComponentTy> {
+            /// Iterate over the components of `self` along with their names.
+            pub fn named_components(self) -> impl Iterator<Item = (&'static str, ComponentTy)> {
+                let components: [ComponentTy; $num_components] = self.into();
+                IntoIterator::into_iter([$(stringify!($compname)),+]).zip(IntoIterator::into_iter(components))
+            }
+        }
+
+        impl<ComponentTy: FloatComponent> $name<ComponentTy> {
+            /// Whether all components of `self` are neither infinite nor NaN.
+            pub fn is_finite(&self) -> bool {
+                $(self.$compname.is_finite())&&+
+            }
+
+            /// Create a new color from its components, returning `None` if any of them are infinite or NaN.
+            pub fn try_new_finite($($compname: ComponentTy),+) -> Option<$name<ComponentTy>> {
+                let col = $name { $($compname,)+ };
+                if col.is_finite() {
+                    Some(col)
+                } else {
+                    None
+                }
+            }
+        }
+
+        impl<ComponentTy: Copy, const N: usize> BlockColor<N> for $name<ComponentTy> {
+            type Block = $name<[ComponentTy; N]>;
+
+            fn into_block(colors: [$name<ComponentTy>; N]) -> $name<[ComponentTy; N]> {
+                $name {
+                    $($compname: colors.map(|col| col.$compname),)+
+                }
+            }
+
+            fn from_block(block: $name<[ComponentTy; N]>) -> [$name<ComponentTy>; N] {
+                core::array::from_fn(|i| $name {
+                    $($compname: block.$compname[i],)+
+                })
+            }
+        }
+
+        macro_rules! impl_alpha_traits {
+            ($alphaty:ident) => {
+                impl<ComponentTy: Copy> From<$alphaty<$name<ComponentTy>>> for $name<ComponentTy> {
+                    fn from(col_alpha: $alphaty<$name<ComponentTy>>) -> $name<ComponentTy> {
                         col_alpha.color
                     }
                 }
 
-                impl<ComponentTy: Copy> From<[ComponentTy; $num_components + 1]> for $alphaty<$name<ComponentTy>> {
-                    fn from([$($compname,)+ alpha]: [ComponentTy; $num_components + 1]) -> $alphaty<$name<ComponentTy>> {
-                        $alphaty {
-                            color: $name::from([$($compname,)+]),
-                            alpha,
-                        }
-                    }
-                }
+                impl<ComponentTy: Copy> From<[ComponentTy; $num_components + 1]> for $alphaty<$name<ComponentTy>> {
+                    fn from([$($compname,)+ alpha]: [ComponentTy; $num_components + 1]) -> $alphaty<$name<ComponentTy>> {
+                        $alphaty {
+                            color: $name::from([$($compname,)+]),
+                            alpha,
+                        }
+                    }
+                }
+
+                #[allow(clippy::from_over_into)]
+                impl<ComponentTy: Copy> Into<[ComponentTy; $num_components + 1]> for $alphaty<$name<ComponentTy>> {
+                    fn into(self) -> [ComponentTy; $num_components + 1] {
+                        let $alphaty {
+                            color,
+                            alpha
+                        } = self;
+
+                        let $name {
+                            $($compname,)+
+                        } = color;
+
+                        [$($compname,)+ alpha]
+                    }
+                }
+
+                impl_ordered_color!($alphaty<$name<ComponentTy>>);
+
+                #[cfg(feature = "serde")]
+                impl<ComponentTy: Copy + ::serde::Serialize> ::serde::Serialize for $alphaty<$name<ComponentTy>> {
+                    fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                        use ::serde::ser::SerializeTuple;
+
+                        let mut tuple = serializer.serialize_tuple($num_components + 1)?;
+                        $(tuple.serialize_element(&self.color.$compname)?;)+
+                        tuple.serialize_element(&self.alpha)?;
+                        tuple.end()
+                    }
+                }
+
+                #[cfg(feature = "serde")]
+                impl<'de, ComponentTy: Copy + ::serde::Deserialize<'de>> ::serde::Deserialize<'de> for $alphaty<$name<ComponentTy>> {
+                    fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                        <[ComponentTy; $num_components + 1] as ::serde::Deserialize<'de>>::deserialize(deserializer).map($alphaty::from)
+                    }
+                }
+
+                assert_array_layout! {
+                    $alphaty<$name<u8>> => [u8; $num_components + 1],
+                    $alphaty<$name<u16>> => [u16; $num_components + 1],
+                    $alphaty<$name<f32>> => [f32; $num_components + 1],
+                    $alphaty<$name<f64>> => [f64; $num_components + 1],
+                }
+
+                impl<ComponentTy: Copy> core::convert::TryFrom<&[ComponentTy]> for $alphaty<$name<ComponentTy>> {
+                    type Error = Error;
+
+                    fn try_from(components: &[ComponentTy]) -> Result<$alphaty<$name<ComponentTy>>, Error> {
+                        let components = <[ComponentTy; $num_components + 1] as core::convert::TryFrom<&[ComponentTy]>>::try_from(components).map_err(|_| Error::WrongComponentCount {
+                            expected: $num_components + 1,
+                            found: components.len(),
+                        })?;
+                        Ok(components.into())
+                    }
+                }
+
+                impl<ComponentTy: Copy> $alphaty<$name<ComponentTy>> {
+                    /// Iterate over the components of `self`, including the alpha component, along with their names.
+                    pub fn named_components(self) -> impl Iterator<Item = (&'static str, ComponentTy)> {
+                        let components: [ComponentTy; $num_components + 1] = self.into();
+                        IntoIterator::into_iter([$(stringify!($compname),)+ "alpha"]).zip(IntoIterator::into_iter(components))
+                    }
+                }
+
+                impl<ComponentTy: FloatComponent> $alphaty<$name<ComponentTy>> {
+                    /// Whether all components of `self`, including the alpha component, are neither infinite nor NaN.
+                    pub fn is_finite(&self) -> bool {
+                        self.color.is_finite() && self.alpha.is_finite()
+                    }
+
+                    /// Create a new color from its color and alpha parts, returning `None` if any
+                    /// components are infinite or NaN.
+                    pub fn try_new_finite(color: $name<ComponentTy>, alpha: ComponentTy) -> Option<$alphaty<$name<ComponentTy>>> {
+                        let col = $alphaty { color, alpha };
+                        if col.is_finite() {
+                            Some(col)
+                        } else {
+                            None
+                        }
+                    }
+                }
+
+                impl<ComponentTy: Copy, const N: usize> BlockColor<N> for $alphaty<$name<ComponentTy>> {
+                    type Block = $alphaty<$name<[ComponentTy; N]>>;
+
+                    fn into_block(colors: [$alphaty<$name<ComponentTy>>; N]) -> $alphaty<$name<[ComponentTy; N]>> {
+                        $alphaty {
+                            color: <$name<ComponentTy> as BlockColor<N>>::into_block(colors.map(|col| col.color)),
+                            alpha: colors.map(|col| col.alpha),
+                        }
+                    }
+
+                    fn from_block(block: $alphaty<$name<[ComponentTy; N]>>) -> [$alphaty<$name<ComponentTy>>; N] {
+                        let colors = <$name<ComponentTy> as BlockColor<N>>::from_block(block.color);
+                        core::array::from_fn(|i| $alphaty {
+                            color: colors[i],
+                            alpha: block.alpha[i],
+                        })
+                    }
+                }
+            }
+        }
+
+        macro_rules! impl_alpha_first_traits {
+            ($alphaty:ident) => {
+                impl<ComponentTy: Copy> From<$alphaty<$name<ComponentTy>>> for $name<ComponentTy> {
+                    fn from(col_alpha: $alphaty<$name<ComponentTy>>) -> $name<ComponentTy> {
+                        col_alpha.color
+                    }
+                }
+
+                impl<ComponentTy: Copy> From<[ComponentTy; $num_components + 1]> for $alphaty<$name<ComponentTy>> {
+                    fn from([alpha, $($compname,)+]: [ComponentTy; $num_components + 1]) -> $alphaty<$name<ComponentTy>> {
+                        $alphaty {
+                            alpha,
+                            color: $name::from([$($compname,)+]),
+                        }
+                    }
+                }
+
+                #[allow(clippy::from_over_into)]
+                impl<ComponentTy: Copy> Into<[ComponentTy; $num_components + 1]> for $alphaty<$name<ComponentTy>> {
+                    fn into(self) -> [ComponentTy; $num_components + 1] {
+                        let $alphaty {
+                            alpha,
+                            color
+                        } = self;
+
+                        let $name {
+                            $($compname,)+
+                        } = color;
+
+                        [alpha, $($compname,)+]
+                    }
+                }
+
+                impl_ordered_color!($alphaty<$name<ComponentTy>>);
+
+                #[cfg(feature = "serde")]
+                impl<ComponentTy: Copy + ::serde::Serialize> ::serde::Serialize for $alphaty<$name<ComponentTy>> {
+                    fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                        use ::serde::ser::SerializeTuple;
+
+                        let mut tuple = serializer.serialize_tuple($num_components + 1)?;
+                        tuple.serialize_element(&self.alpha)?;
+                        $(tuple.serialize_element(&self.color.$compname)?;)+
+                        tuple.end()
+                    }
+                }
+
+                #[cfg(feature = "serde")]
+                impl<'de, ComponentTy: Copy + ::serde::Deserialize<'de>> ::serde::Deserialize<'de> for $alphaty<$name<ComponentTy>> {
+                    fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                        <[ComponentTy; $num_components + 1] as ::serde::Deserialize<'de>>::deserialize(deserializer).map($alphaty::from)
+                    }
+                }
+
+                assert_array_layout! {
+                    $alphaty<$name<u8>> => [u8; $num_components + 1],
+                    $alphaty<$name<u16>> => [u16; $num_components + 1],
+                    $alphaty<$name<f32>> => [f32; $num_components + 1],
+                    $alphaty<$name<f64>> => [f64; $num_components + 1],
+                }
+
+                impl<ComponentTy: Copy> core::convert::TryFrom<&[ComponentTy]> for $alphaty<$name<ComponentTy>> {
+                    type Error = Error;
+
+                    fn try_from(components: &[ComponentTy]) -> Result<$alphaty<$name<ComponentTy>>, Error> {
+                        let components = <[ComponentTy; $num_components + 1] as core::convert::TryFrom<&[ComponentTy]>>::try_from(components).map_err(|_| Error::WrongComponentCount {
+                            expected: $num_components + 1,
+                            found: components.len(),
+                        })?;
+                        Ok(components.into())
+                    }
+                }
+
+                impl<ComponentTy: Copy> $alphaty<$name<ComponentTy>> {
+                    /// Iterate over the components of `self`, including the alpha component, along with their names.
+                    pub fn named_components(self) -> impl Iterator<Item = (&'static str, ComponentTy)> {
+                        let components: [ComponentTy; $num_components + 1] = self.into();
+                        IntoIterator::into_iter(["alpha", $(stringify!($compname),)+]).zip(IntoIterator::into_iter(components))
+                    }
+                }
+
+                impl<ComponentTy: FloatComponent> $alphaty<$name<ComponentTy>> {
+                    /// Whether all components of `self`, including the alpha component, are neither infinite nor NaN.
+                    pub fn is_finite(&self) -> bool {
+                        self.color.is_finite() && self.alpha.is_finite()
+                    }
+
+                    /// Create a new color from its color and alpha parts, returning `None` if any
+                    /// components are infinite or NaN.
+                    pub fn try_new_finite(color: $name<ComponentTy>, alpha: ComponentTy) -> Option<$alphaty<$name<ComponentTy>>> {
+                        let col = $alphaty { color, alpha };
+                        if col.is_finite() {
+                            Some(col)
+                        } else {
+                            None
+                        }
+                    }
+                }
+
+                impl<ComponentTy: Copy, const N: usize> BlockColor<N> for $alphaty<$name<ComponentTy>> {
+                    type Block = $alphaty<$name<[ComponentTy; N]>>;
+
+                    fn into_block(colors: [$alphaty<$name<ComponentTy>>; N]) -> $alphaty<$name<[ComponentTy; N]>> {
+                        $alphaty {
+                            color: <$name<ComponentTy> as BlockColor<N>>::into_block(colors.map(|col| col.color)),
+                            alpha: colors.map(|col| col.alpha),
+                        }
+                    }
+
+                    fn from_block(block: $alphaty<$name<[ComponentTy; N]>>) -> [$alphaty<$name<ComponentTy>>; N] {
+                        let colors = <$name<ComponentTy> as BlockColor<N>>::from_block(block.color);
+                        core::array::from_fn(|i| $alphaty {
+                            color: colors[i],
+                            alpha: block.alpha[i],
+                        })
+                    }
+                }
+            }
+        }
+
+        #[cfg(feature = "wide")]
+        macro_rules! impl_wide_traits {
+            ($simd_ty:ident, $scalar_ty:ty, $lanes:literal) => {
+                impl From<[$name<$scalar_ty>; $lanes]> for $name<wide::$simd_ty> {
+                    fn from(colors: [$name<$scalar_ty>; $lanes]) -> $name<wide::$simd_ty> {
+                        $name {
+                            $($compname: wide::$simd_ty::from(colors.map(|col| col.$compname)),)+
+                        }
+                    }
+                }
+
+                #[allow(clippy::from_over_into)]
+                impl Into<[$name<$scalar_ty>; $lanes]> for $name<wide::$simd_ty> {
+                    fn into(self) -> [$name<$scalar_ty>; $lanes] {
+                        $(let $compname = self.$compname.to_array();)+
+                        core::array::from_fn(|lane| $name {
+                            $($compname: $compname[lane],)+
+                        })
+                    }
+                }
+            }
+        }
+
+        #[cfg(feature = "wide")]
+        impl_wide_traits!(f32x4, f32, 4);
+        #[cfg(feature = "wide")]
+        impl_wide_traits!(f32x8, f32, 8);
+        #[cfg(feature = "wide")]
+        impl_wide_traits!(f64x2, f64, 2);
+        #[cfg(feature = "wide")]
+        impl_wide_traits!(f64x4, f64, 4);
+
+        impl_alpha_traits!(Alpha);
+        impl_alpha_traits!(PremultipliedAlpha);
+        impl_alpha_first_traits!(AlphaFirst);
+        impl_alpha_first_traits!(PremultipliedAlphaFirst);
+
+        #[cfg(feature = "bytemuck")]
+        impl_aligned_pod!($num_components, $name);
+    };
+}
+
+macro_rules! color_spaces {
+    {
+        $($(#[$space_doc:meta])*
+        $space_name:ident<$default_component_ty:ty, $num_components:tt> {
+            $($(#[$comp_doc:meta])+
+            $comp_name:ident,)+
+        })*
+    } => {
+        /// An enum with a variant for each of the color spaces
+        /// supported by the library. Useful for tracking as metadata
+        /// in something like an image type, and for runtime-determined color types.
+        ///
+        /// New spaces are added in minor releases, so this enum is `non_exhaustive`. Code that needs
+        /// to handle every space can use the [`for_each_space`] macro and the metadata accessors on
+        /// this type instead of matching exhaustively.
+        #[repr(u32)]
+        #[non_exhaustive]
+        #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+        pub enum Spaces {
+            $(
+                $(#[$space_doc])*
+                $space_name,
+            )*
+        }
+
+        impl Spaces {
+            pub fn num_components(&self) -> usize {
+                match *self {
+                    $(
+                        Self::$space_name => $num_components,
+                    )*
+                }
+            }
+
+            /// The names of the components of colors in this space, in memory order.
+            pub fn component_names(&self) -> &'static [&'static str] {
+                match *self {
+                    $(
+                        Self::$space_name => &[$(stringify!($comp_name)),+],
+                    )*
+                }
+            }
+        }
+
+        /// Every variant of [`Spaces`], in declaration order, such that `ALL_SPACES[space as usize] == space`.
+        pub(crate) const ALL_SPACES: [Spaces; NUM_SPACES] = [$(Spaces::$space_name),*];
+
+        /// The number of variants of [`Spaces`].
+        pub(crate) const NUM_SPACES: usize = [$(stringify!($space_name)),*].len();
+
+        /// Invokes the given macro once for each of the color spaces supported by the library, in the
+        /// same order as the variants of [`Spaces`].
+        ///
+        /// The macro is invoked as `$callback! { $space, $num_components, [$($component),+] }`,
+        /// where `$space` is the identifier of both the color type and its [`Spaces`] variant,
+        /// `$num_components` is a literal, and `$component` are the identifiers of its components
+        /// in memory order, i.e. `EncodedSrgb, 3, [r, g, b]`. This allows downstream crates to
+        /// mechanically handle all spaces, including ones added in future versions.
+        ///
+        /// ```rust,ignore
+        /// macro_rules! space_name {
+        ///     ($space:ident, $num_components:literal, [$($component:ident),+]) => {
+        ///         impl MySpaceName for cint::$space {
+        ///             const NAME: &'static str = stringify!($space);
+        ///         }
+        ///     };
+        /// }
+        ///
+        /// cint::for_each_space!(space_name);
+        /// ```
+        #[macro_export]
+        macro_rules! for_each_space {
+            ($callback:ident) => {
+                $(
+                    $callback! { $space_name, $num_components, [$($comp_name),+] }
+                )*
+            };
+        }
+
+        /// Zero-sized marker types for each of the color spaces supported by the library, for use
+        /// with the generic [`Color`] type.
+        pub mod space {
+            use crate::{Space, Spaces};
+
+            $(
+                #[doc = concat!("The marker type for the [`", stringify!($space_name), "`][crate::", stringify!($space_name), "] color space.")]
+                #[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+                pub struct $space_name;
+
+                impl Space for $space_name {
+                    const SPACE: Spaces = Spaces::$space_name;
+                    const NUM_COMPONENTS: usize = $num_components;
+                    type Color<ComponentTy: Copy> = crate::$space_name<ComponentTy>;
+                }
+            )*
+        }
+
+        $(
+            impl<ComponentTy: Copy> From<$space_name<ComponentTy>> for Color<space::$space_name, ComponentTy> {
+                fn from(inner: $space_name<ComponentTy>) -> Self {
+                    Color { inner }
+                }
+            }
+
+            impl<ComponentTy: Copy> From<Color<space::$space_name, ComponentTy>> for $space_name<ComponentTy> {
+                fn from(color: Color<space::$space_name, ComponentTy>) -> Self {
+                    color.inner
+                }
+            }
+        )*
+
+        $(
+            color_struct! {
+                $(#[$space_doc])*
+                $space_name<$default_component_ty, $num_components> {
+                    $( $(#[$comp_doc])+
+                    $comp_name,)+
+                }
+            }
+        )*
+    }
+}
+
+color_spaces! {
+    /// A color in the encoded sRGB color space.
+    ///
+    /// This color space uses the sRGB/Rec.709 primaries, D65 white point,
+    /// and sRGB transfer functions. The encoded version is nonlinear, with the
+    /// sRGB OETF, aka "gamma compensation", applied.
+    EncodedSrgb<u8, 3> {
+        /// The red component.
+        r,
+        /// The green component.
+        g,
+        /// The blue component.
+        b,
+    }
+
+    /// A color in the linear (decoded) sRGB color space.
+    ///
+    /// This color space uses the sRGB/Rec.709 primaries, D65 white point,
+    /// and sRGB transfer functions. This version is linear, with the
+    /// sRGB EOTF, aka "inverse gamma compensation", applied in order to
+    /// decode it from [`EncodedSrgb`]
+    LinearSrgb<f32, 3> {
+        /// The red component.
+        r,
+        /// The green component.
+        g,
+        /// The blue component.
+        b,
+    }
+
+    /// A color in the encoded Rec.709/BT.709 color space.
+    ///
+    /// This color space uses the BT.709 primaries, D65 white point,
+    /// and BT.601 (reused in BT.709) transfer function. The encoded version is nonlinear, with the
+    /// BT.601 OETF applied.
+    EncodedRec709<u8, 3> {
+        /// The red component.
+        r,
+        /// The green component.
+        g,
+        /// The blue component.
+        b,
+    }
+
+    /// A color in the Rec.709/BT.709 color space.
+    ///
+    /// This color space uses the BT.709 primaries, D65 white point,
+    /// and BT.601 (reused in BT.709) transfer function. This version is linear, without the
+    /// BT.601 OETF applied.
+    Rec709<f32, 3> {
+        /// The red component.
+        r,
+        /// The green component.
+        g,
+        /// The blue component.
+        b,
+    }
+
+    /// A color in a generic color space that can be represented by 3 components. The user
+    /// is responsible for ensuring that the correct color space is respected.
+    GenericColor3<f32, 3> {
+        /// The first component.
+        x,
+        /// The second component.
+        y,
+        /// The third component.
+        z,
+    }
+
+    /// A color in a generic color space that can be represented by 1 component. The user
+    /// is responsible for ensuring that the correct color space is respected.
+    GenericColor1<f32, 1> {
+        /// The first component.
+        x,
+    }
+
+    /// A single-channel CIE luminance.
+    ///
+    /// This is photopic luminance, weighted by the CIE V(λ) luminous efficiency function of
+    /// cone-mediated (daylight) vision. See [`ScotopicLuminance`] for rod-mediated (night) vision.
+    Luminance<f32, 1> {
+        /// CIE luminance.
+        l,
+    }
+
+    /// A single-channel CIE luma (non-linear transform from luminance).
+    Luma<f32, 1> {
+        /// CIE luminance.
+        l,
+    }
+
+    /// A color in the ACEScg color space.
+    ///
+    /// This color space uses the ACES AP1 primaries and D60 white point.
+    AcesCg<f32, 3> {
+        /// The red component.
+        r,
+        /// The green component.
+        g,
+        /// The blue component.
+        b,
+    }
+
+    /// A color in the ACES 2065-1 color space.
+    ///
+    /// This color space uses the ACES AP0 primaries and D60 white point.
+    Aces2065<f32, 3> {
+        /// The red component.
+        r,
+        /// The green component.
+        g,
+        /// The blue component.
+        b,
+    }
+
+    /// A color in the ACEScc color space.
+    ///
+    /// This color space uses the ACES AP1 primaries and D60 white point
+    /// and a pure logarithmic transfer function.
+    AcesCc<f32, 3> {
+        /// The red component.
+        r,
+        /// The green component.
+        g,
+        /// The blue component.
+        b,
+    }
+
+    /// A color in the ACEScct color space.
+    ///
+    /// This color space uses the ACES AP1 primaries and D60 white point
+    /// and a logarithmic transfer function with a toe such that values
+    /// are able to go negative.
+    AcesCct<f32, 3> {
+        /// The red component.
+        r,
+        /// The green component.
+        g,
+        /// The blue component.
+        b,
+    }
+
+    /// A color in the Display P3 (aka P3 D65) color space.
+    ///
+    /// This color space uses the P3 primaries and D65 white point
+    /// and sRGB transfer functions. This version is linear,
+    /// without the sRGB OETF applied.
+    DisplayP3<f32, 3> {
+        /// The red component.
+        r,
+        /// The green component.
+        g,
+        /// The blue component.
+        b,
+    }
+
+    /// A color in the Display P3 (aka P3 D65) color space.
+    ///
+    /// This color space uses the P3 primaries and D65 white point
+    /// and sRGB transfer functions. This encoded version is nonlinear,
+    /// with the sRGB OETF applied.
+    EncodedDisplayP3<u8, 3> {
+        /// The red component.
+        r,
+        /// The green component.
+        g,
+        /// The blue component.
+        b,
+    }
+
+    /// A color in the DCI-P3 (aka P3 DCI and P3 D60) color space.
+    ///
+    /// If you are looking for the P3 which is used on new Apple displays, see
+    /// [`DisplayP3`] instead.
+    ///
+    /// This color space uses the P3 primaries and D60 white point.
+    DciP3<f32, 3> {
+        /// The red component.
+        r,
+        /// The green component.
+        g,
+        /// The blue component.
+        b,
+    }
+
+    /// A color in the X'Y'Z' color space, a DCI specification used for digital cinema mastering.
+    ///
+    /// This color space uses the CIE XYZ primaries, with special DCI white point and pure 2.6 gamma encoding.
+    DciXYZPrime<f32, 3> {
+        /// The X' component.
+        x,
+        /// The Y' component.
+        y,
+        /// The Z' component.
+        z,
+    }
+
+    /// A color in the BT.2020 color space.
+    ///
+    /// This color space uses the BT.2020 primaries and D65 white point.
+    Bt2020<f32, 3> {
+        /// The red component.
+        r,
+        /// The green component.
+        g,
+        /// The blue component.
+        b,
+    }
+
+    /// A color in the encoded BT.2020 color space.
+    ///
+    /// This color space uses the BT.2020 primaries and D65 white point and
+    /// the BT.2020 transfer functions (equivalent to BT.601 transfer functions
+    /// but with higher precision). This encoded version is nonlinear, with the
+    /// BT.2020/BT.601 OETF applied.
+    ///
+    /// With `f32` components, values are normalized so that 0 is black and 1 is
+    /// nominal peak. See [`EncodedBt2020U16`] for the integer code value form produced by
+    /// 10- and 12-bit video decoders.
+    EncodedBt2020<f32, 3> {
+        /// The red component.
+        r,
+        /// The green component.
+        g,
+        /// The blue component.
+        b,
+    }
+
+    /// A color in the BT.2100 color space.
+    ///
+    /// This color space uses the BT.2020 primaries and D65 white point.
+    Bt2100<f32, 3> {
+        /// The red component.
+        r,
+        /// The green component.
+        g,
+        /// The blue component.
+        b,
+    }
+
+    /// A color in the encoded BT.2100 color space with PQ (Perceptual Quantizer)
+    /// transfer function.
+    ///
+    /// This color space uses the BT.2020 primaries and D65 white point and
+    /// the ST 2084/"PQ" transfer function. It is nonlinear.
+    ///
+    /// See [`EncodedBt2100PQU16`] for the integer code value form.
+    EncodedBt2100PQ<f32, 3> {
+        /// The red component.
+        r,
+        /// The green component.
+        g,
+        /// The blue component.
+        b,
+    }
+
+    /// A color in the encoded BT.2100 color space with HLG (Hybrid Log-Gamma)
+    /// transfer function.
+    ///
+    /// This color space uses the BT.2020 primaries and D65 white point and
+    /// the HLG transfer function. It is nonlinear.
+    ///
+    /// See [`EncodedBt2100HLGU16`] for the integer code value form.
+    EncodedBt2100HLG<f32, 3> {
+        /// The red component.
+        r,
+        /// The green component.
+        g,
+        /// The blue component.
+        b,
+    }
+
+    /// A color in the ICtCp color space with PQ (Perceptual Quantizer)
+    /// nonlinearity.
+    ///
+    /// This color space is based on the BT.2020 primaries and D65 white point,
+    /// but is not an RGB color space. Instead it is a roughly perceptual color
+    /// space meant to more efficiently encode HDR content.
+    ICtCpPQ<f32, 3> {
+        /// The I (intensity) component.
+        i,
+        /// The Ct (chroma-tritan) component.
+        ct,
+        /// The Cp (chroma-protan) component.
+        cp,
+    }
+
+    /// A color in the ICtCp color space with HLG (Hybrid Log-Gamma)
+    /// nonlinearity.
+    ///
+    /// This color space is based on the BT.2020 primaries and D65 white point,
+    /// but is not an RGB color space. Instead it is a roughly perceptual color
+    /// space meant to more efficiently encode HDR content.
+    ICtCpHLG<f32, 3> {
+        /// The I (intensity) component.
+        i,
+        /// The Ct (chroma-tritan) component.
+        ct,
+        /// The Cp (chroma-protan) component.
+        cp,
+    }
+
+    /// A color in the CIE XYZ color space.
+    ///
+    /// This color space uses the CIE XYZ primaries and D65 white point.
+    CieXYZ<f32, 3> {
+        /// The X component.
+        x,
+        /// The Y component.
+        y,
+        /// The Z component.
+        z,
+    }
+
+    /// A color in the CIE L\*a\*b\* color space.
+    CieLab<f32, 3> {
+        /// The L (lightness) component. Varies from 0 to 100.
+        l,
+        /// The a component, representing green-red chroma difference.
+        a,
+        /// The b component, representing blue-yellow chroma difference.
+        b,
+    }
+
+    /// A color in the CIE L\*C\*h° color space.
+    ///
+    /// Colors whose hue is given in degrees from 0 to 360 instead, i.e. CSS `lch()` colors,
+    /// can be marked as such by wrapping them in [`HueDegrees`].
+    CieLCh<f32, 3> {
+        /// The L (lightness) component. Varies from 0 to 100.
+        l,
+        /// The C (chroma) component. Varies from 0 to a hue dependent maximum.
+        c,
+        /// The h (hue) component. Varies from -PI to PI.
+        h,
+    }
+
+    /// A color in the Oklab color space.
+    Oklab<f32, 3> {
+        /// The L (lightness) component. Varies from 0 to 1
+        l,
+        /// The a component, representing green-red chroma difference.
+        a,
+        /// The b component, representing blue-yellow chroma difference.
+        b,
+    }
+
+    /// A color in the Oklch color space (a transformation from Oklab to LCh° coordinates).
+    ///
+    /// Colors whose hue is given in degrees from 0 to 360 instead, i.e. CSS `oklch()` colors,
+    /// can be marked as such by wrapping them in [`HueDegrees`].
+    Oklch<f32, 3> {
+        /// The L (lightness) component. Varies from 0 to 1.
+        l,
+        /// The C (chroma) component. Varies from 0 to a hue dependent maximum.
+        c,
+        /// The h (hue) component. Varies from -PI to PI.
+        h,
+    }
+
+    /// A color in the HSL color space.
+    ///
+    /// Since HSL is a relative color space, it is required to know the RGB space which
+    /// it was transformed from. We define this as the linear sRGB space, as that is
+    /// the most common case. HSL colors derived from another RGB space, i.e. CSS `hsl()` colors
+    /// which are derived from encoded sRGB, can be marked as such with [`DerivedFrom`], as in
+    /// `DerivedFrom<Hsl<f32>, EncodedSrgb<f32>>`.
+    Hsl<f32, 3> {
+        /// The H (hue) component. Varies from 0 to 1.
+        h,
+        /// The S (saturation) component. Varies from 0 to 1.
+        s,
+        /// The L (lightness) component. Varies from 0 to 1.
+        l,
+    }
+
+    /// A color in the HSV color space.
+    ///
+    /// Since HSV is a relative color space, it is required to know the RGB space which
+    /// it was transformed from. We define this as the linear sRGB space, as that is
+    /// the most common case. See [`DerivedFrom`] for HSV colors derived from other RGB spaces.
+    Hsv<f32, 3> {
+        /// The H (hue) component. Varies from 0 to 1.
+        h,
+        /// The S (saturation) component. Varies from 0 to 1.
+        s,
+        /// The V (value) component. Varies from 0 to 1.
+        v,
+    }
+
+    /// A color in the YCbCr color space. See discussion of the difference between YCbCr, YUV, and
+    /// YPbPr in [YCbCr Wikipedia article](https://en.wikipedia.org/wiki/YCbCr)
+    ///
+    /// Since YCbCr is a relative color space, it is required to know the RGB space which
+    /// it was transformed from. We define this as being converted from the LinearSrgb color space.
+    /// The RGB space also determines the matrix coefficients, so i.e. BT.709 video is expressed
+    /// as `DerivedFrom<YCbCr<u8>, Rec709<u8>>` and BT.2020 video as
+    /// `DerivedFrom<YCbCr<u8>, Bt2020<u8>>`.
+    YCbCr<u8, 3> {
+        /// The Y (luminance) component.
+        y,
+        /// The Cb (chroma-blue/yellow) component.
+        cb,
+        /// The Cr (chroma-red/green) component.
+        cr,
+    }
+
+    /// A color in the Y'CbCr color space. See discussion of the difference between YCbCr, Y'CbCr,
+    /// YUV, YPbPr, and Y'PbPr in the [YCbCr Wikipedia article](https://en.wikipedia.org/wiki/YCbCr)
+    ///
+    /// Since Y'CbCr is a relative color space, it is required to know the RGB space which
+    /// it was transformed from. We define this as being converted from the EncodedSrgb color space.
+    /// Video derived with other matrix coefficients can be marked with [`DerivedFrom`], i.e.
+    /// `DerivedFrom<YPrimeCbCr<u8>, EncodedRec709<u8>>` for BT.709 and
+    /// `DerivedFrom<YPrimeCbCr<u8>, EncodedBt601_625<u8>>` for BT.601 video.
+    YPrimeCbCr<u8, 3> {
+        /// The Y' (luma) component.
+        y,
+        /// The Cb (chroma-blue/yellow) component.
+        cb,
+        /// The Cr (chroma-red/green) component.
+        cr,
+    }
+
+    /// A color in the YPbPr color space. See discussion of the difference between YCbCr,
+    /// YUV, YPbPr, and Y'PbPr in the [YCbCr Wikipedia article](https://en.wikipedia.org/wiki/YCbCr)
+    ///
+    /// Since YPbPr is a relative color space, it is required to know the RGB space which
+    /// it was transformed from. We define this as being converted from the LinearSrgb color space.
+    /// See [`DerivedFrom`] for YPbPr colors converted from other RGB spaces.
+    YPbPr<f32, 3> {
+        /// The Y (luminance) component.
+        y,
+        /// The Pb (chroma-blue/yellow) component.
+        pb,
+        /// The Pr (chroma-red/green) component.
+        pr,
+    }
+
+    /// A color in the Y'PbPr color space. See discussion of the difference between YCbCr,
+    /// YUV, YPbPr, and Y'PbPr in the [YCbCr Wikipedia article](https://en.wikipedia.org/wiki/YCbCr)
+    ///
+    /// Since Y'PbPr is a relative color space, it is required to know the RGB space which
+    /// it was transformed from. We define this as being converted from the EncodedSrgb color space.
+    /// See [`DerivedFrom`] for Y'PbPr colors converted from other RGB spaces.
+    YPrimePbPr<f32, 3> {
+        /// The Y' (luma) component.
+        y,
+        /// The Pb (chroma-blue/yellow) component.
+        pb,
+        /// The Pr (chroma-red/green) component.
+        pr,
+    }
+
+    /// A color in the YUV color space. See discussion of the difference between YCbCr, YUV, and
+    /// YPbPr in [YCbCr Wikipedia article](https://en.wikipedia.org/wiki/YCbCr)
+    Yuv<f32, 3> {
+        /// The Y (luminance) component.
+        y,
+        /// The U (chroma-blue/yellow) component.
+        u,
+        /// The V (chroma-red/green) component.
+        v,
+    }
+
+    /// A color in the YCxCz (also called YyCxCz) color space, originally defined in "Optimized
+    /// universal color palette design for error diffusion" by B. W. Kolpatzik and C. A. Bouman.
+    /// Can be thought of as a "linear CIE Lab".
+    YCxCz<f32, 3> {
+        /// The Yy (luminance) component.
+        y,
+        /// The Cx (chroma difference blue/yellow) component
+        cx,
+        /// The Cz (chroma difference red/green) component
+        cz,
+    }
 
-                #[allow(clippy::from_over_into)]
-                impl<ComponentTy: Copy> Into<[ComponentTy; $num_components + 1]> for $alphaty<$name<ComponentTy>> {
-                    fn into(self) -> [ComponentTy; $num_components + 1] {
-                        let $alphaty {
-                            color,
-                            alpha
-                        } = self;
+    /// A color in the YIQ color space, as used by the NTSC analog television standard.
+    ///
+    /// Since YIQ is a relative color space, it is required to know the RGB space which
+    /// it was transformed from. We define this as being converted from the EncodedSrgb color space
+    /// using the BT.601 luma coefficients.
+    Yiq<f32, 3> {
+        /// The Y (luma) component.
+        y,
+        /// The I (in-phase, orange/blue) component.
+        i,
+        /// The Q (quadrature, purple/green) component.
+        q,
+    }
 
-                        let $name {
-                            $($compname,)+
-                        } = color;
+    /// A color in the YDbDr color space, as used by the SECAM analog television standard.
+    ///
+    /// Since YDbDr is a relative color space, it is required to know the RGB space which
+    /// it was transformed from. We define this as being converted from the EncodedSrgb color space
+    /// using the BT.601 luma coefficients.
+    YDbDr<f32, 3> {
+        /// The Y (luma) component.
+        y,
+        /// The Db (chroma-blue/yellow) component.
+        db,
+        /// The Dr (chroma-red/green) component.
+        dr,
+    }
 
-                        [$($compname,)+ alpha]
-                    }
-                }
+    /// A color in the xvYCC (extended-gamut YCC) color space, defined in IEC 61966-2-4.
+    ///
+    /// This color space uses the BT.709 primaries, D65 white point, and BT.709 transfer
+    /// function, extended to negative and greater-than-one values. Unlike [`YPrimeCbCr`], the
+    /// Cb and Cr components are allowed to exceed their nominal range in order to
+    /// represent colors outside of the BT.709 gamut.
+    XvYcc<f32, 3> {
+        /// The Y' (luma) component.
+        y,
+        /// The Cb (chroma-blue/yellow) component.
+        cb,
+        /// The Cr (chroma-red/green) component.
+        cr,
+    }
 
-                impl<ComponentTy: Copy> AsRef<[ComponentTy; $num_components + 1]> for $alphaty<$name<ComponentTy>> {
-                    fn as_ref(&self) -> &[ComponentTy; $num_components + 1] {
-                        // SAFETY: same layout is guaranteed by repr C
-                        unsafe { &*(self as *const $alphaty<$name<ComponentTy>> as *const [ComponentTy; $num_components + 1]) }
-                    }
-                }
+    /// A color in the HSI color space.
+    ///
+    /// Since HSI is a relative color space, it is required to know the RGB space which
+    /// it was transformed from. We define this as the linear sRGB space, as that is
+    /// the most common case.
+    Hsi<f32, 3> {
+        /// The H (hue) component. Varies from 0 to 1.
+        h,
+        /// The S (saturation) component. Varies from 0 to 1.
+        s,
+        /// The I (intensity) component. Varies from 0 to 1.
+        i,
+    }
 
-                impl<ComponentTy: Copy> AsMut<[ComponentTy; $num_components + 1]> for $alphaty<$name<ComponentTy>> {
-                    fn as_mut(&mut self) -> &mut [ComponentTy; $num_components + 1] {
-                        // SAFETY: same layout is guaranteed by repr C
-                        unsafe { &mut *(self as *mut $alphaty<$name<ComponentTy>> as *mut [ComponentTy; $num_components + 1]) }
-                    }
-                }
-            }
-        }
+    /// A color in the ProPhoto RGB (aka ROMM RGB) color space.
+    ///
+    /// This color space uses the ROMM primaries and D50 white point, as defined in
+    /// ISO 22028-2. This version is linear, without the ROMM OETF applied.
+    ProPhotoRgb<f32, 3> {
+        /// The red component.
+        r,
+        /// The green component.
+        g,
+        /// The blue component.
+        b,
+    }
 
-        impl_alpha_traits!(Alpha);
-        impl_alpha_traits!(PremultipliedAlpha);
-    };
-}
+    /// A color in the encoded ProPhoto RGB (aka ROMM RGB) color space.
+    ///
+    /// This color space uses the ROMM primaries and D50 white point, as defined in
+    /// ISO 22028-2. This encoded version is nonlinear, with the ROMM OETF (a 1/1.8 power
+    /// function with a short linear segment near black) applied. It defaults to 16-bit
+    /// components as that is how ProPhoto images are most commonly stored.
+    EncodedProPhotoRgb<u16, 3> {
+        /// The red component.
+        r,
+        /// The green component.
+        g,
+        /// The blue component.
+        b,
+    }
 
-macro_rules! color_spaces {
-    {
-        $($(#[$space_doc:meta])*
-        $space_name:ident<$default_component_ty:ty, $num_components:literal> {
-            $($(#[$comp_doc:meta])+
-            $comp_name:ident,)+
-        })*
-    } => {
-        /// An enum with a variant for each of the color spaces
-        /// supported by the library. Useful for tracking as metadata
-        /// in something like an image type, and for runtime-determined color types.
-        #[repr(u32)]
-        #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
-        pub enum Spaces {
-            $(
-                $(#[$space_doc])*
-                $space_name,
-            )*
-        }
+    /// A color in the Adobe RGB (1998) color space.
+    ///
+    /// This color space uses the Adobe RGB (1998) primaries and D65 white point.
+    /// This version is linear, without the Adobe RGB (1998) gamma encoding applied.
+    AdobeRgb1998<f32, 3> {
+        /// The red component.
+        r,
+        /// The green component.
+        g,
+        /// The blue component.
+        b,
+    }
 
-        impl Spaces {
-            pub fn num_components(&self) -> usize {
-                match *self {
-                    $(
-                        Self::$space_name => $num_components,
-                    )*
-                }
-            }
-        }
+    /// A color in the encoded Adobe RGB (1998) color space.
+    ///
+    /// This color space uses the Adobe RGB (1998) primaries and D65 white point.
+    /// This encoded version is nonlinear, with a pure 563/256 (approximately 2.2) gamma
+    /// encoding applied.
+    EncodedAdobeRgb1998<u8, 3> {
+        /// The red component.
+        r,
+        /// The green component.
+        g,
+        /// The blue component.
+        b,
+    }
 
-        $(
-            color_struct! {
-                $(#[$space_doc])*
-                $space_name<$default_component_ty, $num_components> {
-                    $( $(#[$comp_doc])+
-                    $comp_name,)+
-                }
-            }
-        )*
+    /// A color in the Adobe Wide Gamut RGB color space.
+    ///
+    /// This color space uses the Adobe Wide Gamut RGB primaries and D50 white point.
+    /// This version is linear, without the Wide Gamut RGB gamma encoding applied.
+    WideGamutRgb<f32, 3> {
+        /// The red component.
+        r,
+        /// The green component.
+        g,
+        /// The blue component.
+        b,
     }
-}
 
-color_spaces! {
-    /// A color in the encoded sRGB color space.
+    /// A color in the encoded Adobe Wide Gamut RGB color space.
     ///
-    /// This color space uses the sRGB/Rec.709 primaries, D65 white point,
-    /// and sRGB transfer functions. The encoded version is nonlinear, with the
-    /// sRGB OETF, aka "gamma compensation", applied.
-    EncodedSrgb<u8, 3> {
+    /// This color space uses the Adobe Wide Gamut RGB primaries and D50 white point.
+    /// This encoded version is nonlinear, with a pure 563/256 (approximately 2.2) gamma
+    /// encoding applied. It defaults to 16-bit components, as 8 bits are not enough
+    /// to avoid banding across such a wide gamut.
+    EncodedWideGamutRgb<u16, 3> {
         /// The red component.
         r,
         /// The green component.
@@ -306,13 +2366,12 @@ color_spaces! {
         b,
     }
 
-    /// A color in the linear (decoded) sRGB color space.
+    /// A color in the Display P3 (aka P3 D65) color space with PQ (Perceptual Quantizer)
+    /// transfer function.
     ///
-    /// This color space uses the sRGB/Rec.709 primaries, D65 white point,
-    /// and sRGB transfer functions. This version is linear, with the
-    /// sRGB EOTF, aka "inverse gamma compensation", applied in order to
-    /// decode it from [`EncodedSrgb`]
-    LinearSrgb<f32, 3> {
+    /// This color space uses the P3 primaries and D65 white point and
+    /// the ST 2084/"PQ" transfer function. It is nonlinear.
+    EncodedDisplayP3PQ<f32, 3> {
         /// The red component.
         r,
         /// The green component.
@@ -321,12 +2380,12 @@ color_spaces! {
         b,
     }
 
-    /// A color in the encoded Rec.709/BT.709 color space.
+    /// A color in the encoded DCI-P3 (aka P3 DCI and P3 D60) color space.
     ///
-    /// This color space uses the BT.709 primaries, D65 white point,
-    /// and BT.601 (reused in BT.709) transfer function. The encoded version is nonlinear, with the
-    /// BT.601 OETF applied.
-    EncodedRec709<u8, 3> {
+    /// This color space uses the P3 primaries and D60 white point. This encoded version
+    /// is nonlinear, with a pure 2.6 gamma encoding applied, as used for digital cinema
+    /// mastering. See [`EncodedDciP3U16`] for the integer form used by 12-bit deliverables.
+    EncodedDciP3<f32, 3> {
         /// The red component.
         r,
         /// The green component.
@@ -335,12 +2394,14 @@ color_spaces! {
         b,
     }
 
-    /// A color in the Rec.709/BT.709 color space.
+    /// A color in the scRGB color space.
     ///
-    /// This color space uses the BT.709 primaries, D65 white point,
-    /// and BT.601 (reused in BT.709) transfer function. This version is linear, without the
-    /// BT.601 OETF applied.
-    Rec709<f32, 3> {
+    /// This color space uses the sRGB/Rec.709 primaries and D65 white point. It is linear
+    /// and allows values outside of the 0 to 1 range (including negative values) in order to
+    /// represent colors outside of the sRGB gamut and brighter than SDR reference white.
+    /// A value of 1.0 corresponds to the SDR reference white of 80 nits, as used by Windows
+    /// HDR composition.
+    ScRgb<f32, 3> {
         /// The red component.
         r,
         /// The green component.
@@ -349,40 +2410,110 @@ color_spaces! {
         b,
     }
 
-    /// A color in a generic color space that can be represented by 3 components. The user
-    /// is responsible for ensuring that the correct color space is respected.
-    GenericColor3<f32, 3> {
-        /// The first component.
-        x,
-        /// The second component.
-        y,
-        /// The third component.
-        z,
+    /// A color in the encoded extended-range sRGB color space.
+    ///
+    /// This color space uses the sRGB/Rec.709 primaries, D65 white point,
+    /// and sRGB transfer functions. Values are allowed outside of the 0 to 1 range, with the
+    /// sRGB OETF mirrored for negative values, as used by Apple's extended sRGB. It is nonlinear.
+    EncodedExtendedSrgb<f32, 3> {
+        /// The red component.
+        r,
+        /// The green component.
+        g,
+        /// The blue component.
+        b,
     }
 
-    /// A color in a generic color space that can be represented by 1 component. The user
-    /// is responsible for ensuring that the correct color space is respected.
-    GenericColor1<f32, 1> {
-        /// The first component.
-        x,
+    /// A color in the BT.601 625-line (PAL/SECAM) color space.
+    ///
+    /// This color space uses the BT.601 625-line primaries (equivalent to BT.470 System B/G
+    /// and EBU Tech. 3213), D65 white point, and BT.601 transfer function. This version is
+    /// linear, without the BT.601 OETF applied.
+    Bt601_625<f32, 3> {
+        /// The red component.
+        r,
+        /// The green component.
+        g,
+        /// The blue component.
+        b,
     }
 
-    /// A single-channel CIE luminance.
-    Luminance<f32, 1> {
-        /// CIE luminance.
-        l,
+    /// A color in the encoded BT.601 625-line (PAL/SECAM) color space.
+    ///
+    /// This color space uses the BT.601 625-line primaries (equivalent to BT.470 System B/G
+    /// and EBU Tech. 3213), D65 white point, and BT.601 transfer function. The encoded version
+    /// is nonlinear, with the BT.601 OETF applied.
+    EncodedBt601_625<u8, 3> {
+        /// The red component.
+        r,
+        /// The green component.
+        g,
+        /// The blue component.
+        b,
     }
 
-    /// A single-channel CIE luma (non-linear transform from luminance).
-    Luma<f32, 1> {
-        /// CIE luminance.
-        l,
+    /// A color in the BT.601 525-line (NTSC) color space.
+    ///
+    /// This color space uses the BT.601 525-line primaries (equivalent to SMPTE 170M, aka
+    /// "SMPTE C"), D65 white point, and BT.601 transfer function. This version is
+    /// linear, without the BT.601 OETF applied.
+    Bt601_525<f32, 3> {
+        /// The red component.
+        r,
+        /// The green component.
+        g,
+        /// The blue component.
+        b,
     }
 
-    /// A color in the ACEScg color space.
+    /// A color in the encoded BT.601 525-line (NTSC) color space.
     ///
-    /// This color space uses the ACES AP1 primaries and D60 white point.
-    AcesCg<f32, 3> {
+    /// This color space uses the BT.601 525-line primaries (equivalent to SMPTE 170M, aka
+    /// "SMPTE C"), D65 white point, and BT.601 transfer function. The encoded version
+    /// is nonlinear, with the BT.601 OETF applied.
+    EncodedBt601_525<u8, 3> {
+        /// The red component.
+        r,
+        /// The green component.
+        g,
+        /// The blue component.
+        b,
+    }
+
+    /// A color in the Rec.709/BT.709 color space encoded for display with BT.1886.
+    ///
+    /// This color space uses the BT.709 primaries and D65 white point. This encoded version
+    /// is nonlinear and display-referred, meant to be decoded with the BT.1886 reference
+    /// display EOTF (a 2.4 gamma), rather than being encoded with the camera OETF as
+    /// [`EncodedRec709`] is.
+    EncodedBt1886<f32, 3> {
+        /// The red component.
+        r,
+        /// The green component.
+        g,
+        /// The blue component.
+        b,
+    }
+
+    /// A color in the ARRI LogC3 color space.
+    ///
+    /// This color space uses the ARRI Wide Gamut 3 primaries and D65 white point
+    /// and the ARRI LogC3 (EI 800) logarithmic transfer function. It is nonlinear.
+    ArriLogC3<f32, 3> {
+        /// The red component.
+        r,
+        /// The green component.
+        g,
+        /// The blue component.
+        b,
+    }
+
+    /// A color in the ARRI LogC4 color space.
+    ///
+    /// This color space uses the ARRI Wide Gamut 4 primaries and D65 white point
+    /// and the ARRI LogC4 logarithmic transfer function. It is nonlinear, and is not
+    /// compatible with [`ArriLogC3`].
+    ArriLogC4<f32, 3> {
         /// The red component.
         r,
         /// The green component.
@@ -391,10 +2522,11 @@ color_spaces! {
         b,
     }
 
-    /// A color in the ACES 2065-1 color space.
+    /// A color in the Sony S-Log3 color space.
     ///
-    /// This color space uses the ACES AP0 primaries and D60 white point.
-    Aces2065<f32, 3> {
+    /// This color space uses the Sony S-Gamut3 primaries and D65 white point
+    /// and the S-Log3 logarithmic transfer function. It is nonlinear.
+    SonySLog3<f32, 3> {
         /// The red component.
         r,
         /// The green component.
@@ -403,11 +2535,12 @@ color_spaces! {
         b,
     }
 
-    /// A color in the ACEScc color space.
+    /// A color in the Sony S-Log3 color space with S-Gamut3.Cine primaries.
     ///
-    /// This color space uses the ACES AP1 primaries and D60 white point
-    /// and a pure logarithmic transfer function.
-    AcesCc<f32, 3> {
+    /// This color space uses the Sony S-Gamut3.Cine primaries and D65 white point
+    /// and the S-Log3 logarithmic transfer function. It is nonlinear. S-Gamut3.Cine is a
+    /// smaller gamut than the S-Gamut3 used by [`SonySLog3`].
+    SonySLog3Cine<f32, 3> {
         /// The red component.
         r,
         /// The green component.
@@ -416,12 +2549,11 @@ color_spaces! {
         b,
     }
 
-    /// A color in the ACEScct color space.
+    /// A color in the Panasonic V-Log color space.
     ///
-    /// This color space uses the ACES AP1 primaries and D60 white point
-    /// and a logarithmic transfer function with a toe such that values
-    /// are able to go negative.
-    AcesCct<f32, 3> {
+    /// This color space uses the Panasonic V-Gamut primaries and D65 white point
+    /// and the V-Log logarithmic transfer function. It is nonlinear.
+    PanasonicVLog<f32, 3> {
         /// The red component.
         r,
         /// The green component.
@@ -430,12 +2562,11 @@ color_spaces! {
         b,
     }
 
-    /// A color in the Display P3 (aka P3 D65) color space.
+    /// A color in the RED Log3G10 color space.
     ///
-    /// This color space uses the P3 primaries and D65 white point
-    /// and sRGB transfer functions. This version is linear,
-    /// without the sRGB OETF applied.
-    DisplayP3<f32, 3> {
+    /// This color space uses the REDWideGamutRGB primaries and D65 white point
+    /// and the Log3G10 logarithmic transfer function. It is nonlinear.
+    RedLog3G10<f32, 3> {
         /// The red component.
         r,
         /// The green component.
@@ -444,12 +2575,11 @@ color_spaces! {
         b,
     }
 
-    /// A color in the Display P3 (aka P3 D65) color space.
+    /// A color in the Canon Log 3 color space.
     ///
-    /// This color space uses the P3 primaries and D65 white point
-    /// and sRGB transfer functions. This encoded version is nonlinear,
-    /// with the sRGB OETF applied.
-    EncodedDisplayP3<u8, 3> {
+    /// This color space uses the Canon Cinema Gamut primaries and D65 white point
+    /// and the Canon Log 3 logarithmic transfer function. It is nonlinear.
+    CanonLog3<f32, 3> {
         /// The red component.
         r,
         /// The green component.
@@ -458,13 +2588,11 @@ color_spaces! {
         b,
     }
 
-    /// A color in the DCI-P3 (aka P3 DCI and P3 D60) color space.
+    /// A color in the Blackmagic Film Generation 5 color space.
     ///
-    /// If you are looking for the P3 which is used on new Apple displays, see
-    /// [`DisplayP3`] instead.
-    ///
-    /// This color space uses the P3 primaries and D60 white point.
-    DciP3<f32, 3> {
+    /// This color space uses the Blackmagic Wide Gamut Generation 5 primaries and D65 white point
+    /// and the Blackmagic Film Generation 5 logarithmic transfer function. It is nonlinear.
+    BlackmagicFilmGen5<f32, 3> {
         /// The red component.
         r,
         /// The green component.
@@ -473,22 +2601,11 @@ color_spaces! {
         b,
     }
 
-    /// A color in the X'Y'Z' color space, a DCI specification used for digital cinema mastering.
-    ///
-    /// This color space uses the CIE XYZ primaries, with special DCI white point and pure 2.6 gamma encoding.
-    DciXYZPrime<f32, 3> {
-        /// The X' component.
-        x,
-        /// The Y' component.
-        y,
-        /// The Z' component.
-        z,
-    }
-
-    /// A color in the BT.2020 color space.
+    /// A color in the DaVinci Intermediate color space.
     ///
-    /// This color space uses the BT.2020 primaries and D65 white point.
-    Bt2020<f32, 3> {
+    /// This color space uses the DaVinci Wide Gamut primaries and D65 white point
+    /// and the DaVinci Intermediate logarithmic transfer function. It is nonlinear.
+    DaVinciIntermediate<f32, 3> {
         /// The red component.
         r,
         /// The green component.
@@ -497,13 +2614,11 @@ color_spaces! {
         b,
     }
 
-    /// A color in the encoded BT.2020 color space.
+    /// A color in the FilmLight T-Log color space with E-Gamut primaries.
     ///
-    /// This color space uses the BT.2020 primaries and D65 white point and
-    /// the BT.2020 transfer functions (equivalent to BT.601 transfer functions
-    /// but with higher precision). This encoded version is nonlinear, with the
-    /// BT.2020/BT.601 OETF applied.
-    EncodedBt2020<f32, 3> {
+    /// This color space uses the FilmLight E-Gamut primaries and D65 white point
+    /// and the T-Log logarithmic transfer function. It is nonlinear.
+    FilmLightTLogEGamut<f32, 3> {
         /// The red component.
         r,
         /// The green component.
@@ -512,10 +2627,11 @@ color_spaces! {
         b,
     }
 
-    /// A color in the BT.2100 color space.
+    /// A color in the DJI D-Log color space.
     ///
-    /// This color space uses the BT.2020 primaries and D65 white point.
-    Bt2100<f32, 3> {
+    /// This color space uses the DJI D-Gamut primaries and D65 white point
+    /// and the D-Log logarithmic transfer function. It is nonlinear.
+    DjiDLog<f32, 3> {
         /// The red component.
         r,
         /// The green component.
@@ -524,12 +2640,11 @@ color_spaces! {
         b,
     }
 
-    /// A color in the encoded BT.2100 color space with PQ (Perceptual Quantizer)
-    /// transfer function.
+    /// A color in the GoPro Protune Flat color space.
     ///
-    /// This color space uses the BT.2020 primaries and D65 white point and
-    /// the ST 2084/"PQ" transfer function. It is nonlinear.
-    EncodedBt2100PQ<f32, 3> {
+    /// This color space uses the GoPro Protune Native primaries and D65 white point
+    /// and the Protune Flat logarithmic transfer function. It is nonlinear.
+    GoProProtune<f32, 3> {
         /// The red component.
         r,
         /// The green component.
@@ -538,12 +2653,12 @@ color_spaces! {
         b,
     }
 
-    /// A color in the encoded BT.2100 color space with HLG (Hybrid Log-Gamma)
-    /// transfer function.
+    /// A color in the Cineon printing density log color space, as used by DPX film scans.
     ///
-    /// This color space uses the BT.2020 primaries and D65 white point and
-    /// the HLG transfer function. It is nonlinear.
-    EncodedBt2100HLG<f32, 3> {
+    /// This color space encodes printing density logarithmically, as defined by Kodak's Cineon
+    /// system. Components hold 10-bit code values (0 to 1023) in the low bits of each component,
+    /// with 95 representing the black point and 685 representing the white point.
+    CineonLog<u16, 3> {
         /// The red component.
         r,
         /// The green component.
@@ -552,192 +2667,111 @@ color_spaces! {
         b,
     }
 
-    /// A color in the ICtCp color space with PQ (Perceptual Quantizer)
-    /// nonlinearity.
-    ///
-    /// This color space is based on the BT.2020 primaries and D65 white point,
-    /// but is not an RGB color space. Instead it is a roughly perceptual color
-    /// space meant to more efficiently encode HDR content.
-    ICtCpPQ<f32, 3> {
-        /// The I (intensity) component.
-        i,
-        /// The Ct (chroma-tritan) component.
-        ct,
-        /// The Cp (chroma-protan) component.
-        cp,
-    }
-
-    /// A color in the ICtCp color space with HLG (Hybrid Log-Gamma)
-    /// nonlinearity.
+    /// A color in the Fujifilm F-Log color space.
     ///
-    /// This color space is based on the BT.2020 primaries and D65 white point,
-    /// but is not an RGB color space. Instead it is a roughly perceptual color
-    /// space meant to more efficiently encode HDR content.
-    ICtCpHLG<f32, 3> {
-        /// The I (intensity) component.
-        i,
-        /// The Ct (chroma-tritan) component.
-        ct,
-        /// The Cp (chroma-protan) component.
-        cp,
+    /// This color space uses the Fujifilm F-Gamut primaries (equivalent to the BT.2020 primaries)
+    /// and D65 white point and the F-Log logarithmic transfer function. It is nonlinear.
+    FujiFLog<f32, 3> {
+        /// The red component.
+        r,
+        /// The green component.
+        g,
+        /// The blue component.
+        b,
     }
 
-    /// A color in the CIE XYZ color space.
+    /// A color in the Fujifilm F-Log2 color space.
     ///
-    /// This color space uses the CIE XYZ primaries and D65 white point.
-    CieXYZ<f32, 3> {
-        /// The X component.
-        x,
-        /// The Y component.
-        y,
-        /// The Z component.
-        z,
-    }
-
-    /// A color in the CIE L\*a\*b\* color space.
-    CieLab<f32, 3> {
-        /// The L (lightness) component. Varies from 0 to 100.
-        l,
-        /// The a component, representing green-red chroma difference.
-        a,
-        /// The b component, representing blue-yellow chroma difference.
+    /// This color space uses the Fujifilm F-Gamut primaries (equivalent to the BT.2020 primaries)
+    /// and D65 white point and the F-Log2 logarithmic transfer function. It is nonlinear, and is
+    /// not compatible with [`FujiFLog`].
+    FujiFLog2<f32, 3> {
+        /// The red component.
+        r,
+        /// The green component.
+        g,
+        /// The blue component.
         b,
     }
 
-    /// A color in the CIE L\*C\*h° color space.
-    CieLCh<f32, 3> {
-        /// The L (lightness) component. Varies from 0 to 100.
-        l,
-        /// The C (chroma) component. Varies from 0 to a hue dependent maximum.
-        c,
-        /// The h (hue) component. Varies from -PI to PI.
-        h,
-    }
-
-    /// A color in the Oklab color space.
-    Oklab<f32, 3> {
-        /// The L (lightness) component. Varies from 0 to 1
-        l,
-        /// The a component, representing green-red chroma difference.
-        a,
-        /// The b component, representing blue-yellow chroma difference.
+    /// A color in the Nikon N-Log color space.
+    ///
+    /// This color space uses the BT.2020 primaries and D65 white point
+    /// and the N-Log logarithmic transfer function. It is nonlinear.
+    NikonNLog<f32, 3> {
+        /// The red component.
+        r,
+        /// The green component.
+        g,
+        /// The blue component.
         b,
     }
 
-    /// A color in the Oklch color space (a transformation from Oklab to LCh° coordinates).
-    Oklch<f32, 3> {
-        /// The L (lightness) component. Varies from 0 to 1.
-        l,
-        /// The C (chroma) component. Varies from 0 to a hue dependent maximum.
-        c,
-        /// The h (hue) component. Varies from -PI to PI.
-        h,
-    }
-
-    /// A color in the HSL color space.
+    /// A single-channel CIE scotopic luminance.
     ///
-    /// Since HSL is a relative color space, it is required to know the RGB space which
-    /// it was transformed from. We define this as the linear sRGB space, as that is
-    /// the most common case.
-    Hsl<f32, 3> {
-        /// The H (hue) component. Varies from 0 to 1.
-        h,
-        /// The S (saturation) component. Varies from 0 to 1.
-        s,
-        /// The L (lightness) component. Varies from 0 to 1.
+    /// This is luminance weighted by the CIE V'(λ) luminous efficiency function of rod-mediated
+    /// (night) vision, in scotopic candelas per square meter. It can't be derived from photopic
+    /// [`Luminance`] or [`CieXYZ`], so the two must not be mixed up. Mesopic (twilight) luminance
+    /// is computed from a photopic and a scotopic luminance as described in CIE 191.
+    ScotopicLuminance<f32, 1> {
+        /// CIE scotopic luminance.
         l,
     }
 
-    /// A color in the HSV color space.
-    ///
-    /// Since HSV is a relative color space, it is required to know the RGB space which
-    /// it was transformed from. We define this as the linear sRGB space, as that is
-    /// the most common case.
-    Hsv<f32, 3> {
-        /// The H (hue) component. Varies from 0 to 1.
-        h,
-        /// The S (saturation) component. Varies from 0 to 1.
-        s,
-        /// The V (value) component. Varies from 0 to 1.
-        v,
-    }
-
-    /// A color in the YCbCr color space. See discussion of the difference between YCbCr, YUV, and
-    /// YPbPr in [YCbCr Wikipedia article](https://en.wikipedia.org/wiki/YCbCr)
+    /// The five α-opic quantities defined in CIE S 026, i.e. α-opic irradiances or the
+    /// corresponding equivalent daylight (D65) illuminances (EDI), as computed by
+    /// circadian-lighting tools.
     ///
-    /// Since YCbCr is a relative color space, it is required to know the RGB space which
-    /// it was transformed from. We define this as being converted from the LinearSrgb color space.
-    YCbCr<u8, 3> {
-        /// The Y (luminance) component.
-        y,
-        /// The Cb (chroma-blue/yellow) component.
-        cb,
-        /// The Cr (chroma-red/green) component.
-        cr,
+    /// Each component weights the same spectrum by the action spectrum of one photoreceptor type.
+    /// Like [`ScotopicLuminance`], these can't be derived from or converted to any other space.
+    AlphaOpic<f32, 5> {
+        /// The S-cone-opic quantity.
+        sc,
+        /// The M-cone-opic quantity.
+        mc,
+        /// The L-cone-opic quantity.
+        lc,
+        /// The rhodopic quantity.
+        rh,
+        /// The melanopic quantity, i.e. melanopic irradiance or melanopic EDI.
+        mel,
     }
 
-    /// A color in the Y'CbCr color space. See discussion of the difference between YCbCr, Y'CbCr,
-    /// YUV, YPbPr, and Y'PbPr in the [YCbCr Wikipedia article](https://en.wikipedia.org/wiki/YCbCr)
+    /// A color in an RGB space defined at runtime by its primaries, white point, and a pure gamma
+    /// transfer function, as specified by legacy formats and files without an ICC profile.
     ///
-    /// Since Y'CbCr is a relative color space, it is required to know the RGB space which
-    /// it was transformed from. We define this as being converted from the EncodedSrgb color space.
-    YPrimeCbCr<u8, 3> {
-        /// The Y' (luma) component.
-        y,
-        /// The Cb (chroma-blue/yellow) component.
-        cb,
-        /// The Cr (chroma-red/green) component.
-        cr,
+    /// The space is described by a [`GammaRgbParameters`], which must be carried alongside the
+    /// colors, i.e. per image. This allows such colors to be passed around without mislabeling
+    /// them as [`EncodedSrgb`]. This version is nonlinear, with the gamma applied.
+    GammaRgb<u8, 3> {
+        /// The red component.
+        r,
+        /// The green component.
+        g,
+        /// The blue component.
+        b,
     }
+}
 
-    /// A color in the YPbPr color space. See discussion of the difference between YCbCr,
-    /// YUV, YPbPr, and Y'PbPr in the [YCbCr Wikipedia article](https://en.wikipedia.org/wiki/YCbCr)
-    ///
-    /// Since YPbPr is a relative color space, it is required to know the RGB space which
-    /// it was transformed from. We define this as being converted from the LinearSrgb color space.
-    YPbPr<f32, 3> {
-        /// The Y (luminance) component.
-        y,
-        /// The Pb (chroma-blue/yellow) component.
-        pb,
-        /// The Pr (chroma-red/green) component.
-        pr,
-    }
+/// An [`EncodedDciP3`] color holding 12-bit integer code values (0 to 4095) in `u16` components,
+/// as used by digital cinema deliverables.
+pub type EncodedDciP3U16 = EncodedDciP3<u16>;
 
-    /// A color in the Y'PbPr color space. See discussion of the difference between YCbCr,
-    /// YUV, YPbPr, and Y'PbPr in the [YCbCr Wikipedia article](https://en.wikipedia.org/wiki/YCbCr)
-    ///
-    /// Since Y'PbPr is a relative color space, it is required to know the RGB space which
-    /// it was transformed from. We define this as being converted from the EncodedSrgb color space.
-    YPrimePbPr<f32, 3> {
-        /// The Y' (luma) component.
-        y,
-        /// The Pb (chroma-blue/yellow) component.
-        pb,
-        /// The Pr (chroma-red/green) component.
-        pr,
-    }
+/// An [`EncodedBt2020`] color holding 10- or 12-bit integer code values in `u16` components,
+/// as produced by video decoders.
+///
+/// Code values are stored in the low bits of each component (i.e. 0 to 1023 for 10-bit
+/// video and 0 to 4095 for 12-bit video), and are not rescaled to the full `u16` range.
+/// Whether the values use the full range or the limited (video) range is not tracked by
+/// this type.
+pub type EncodedBt2020U16 = EncodedBt2020<u16>;
 
-    /// A color in the YUV color space. See discussion of the difference between YCbCr, YUV, and
-    /// YPbPr in [YCbCr Wikipedia article](https://en.wikipedia.org/wiki/YCbCr)
-    Yuv<f32, 3> {
-        /// The Y (luminance) component.
-        y,
-        /// The U (chroma-blue/yellow) component.
-        u,
-        /// The V (chroma-red/green) component.
-        v,
-    }
+/// An [`EncodedBt2100PQ`] color holding 10- or 12-bit integer code values in `u16` components.
+///
+/// Code values are stored the same way as in [`EncodedBt2020U16`].
+pub type EncodedBt2100PQU16 = EncodedBt2100PQ<u16>;
 
-    /// A color in the YCxCz (also called YyCxCz) color space, originally defined in "Optimized
-    /// universal color palette design for error diffusion" by B. W. Kolpatzik and C. A. Bouman.
-    /// Can be thought of as a "linear CIE Lab".
-    YCxCz<f32, 3> {
-        /// The Yy (luminance) component.
-        y,
-        /// The Cx (chroma difference blue/yellow) component
-        cx,
-        /// The Cz (chroma difference red/green) component
-        cz,
-    }
-}
+/// An [`EncodedBt2100HLG`] color holding 10- or 12-bit integer code values in `u16` components.
+///
+/// Code values are stored the same way as in [`EncodedBt2020U16`].
+pub type EncodedBt2100HLGU16 = EncodedBt2100HLG<u16>;
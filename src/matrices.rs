@@ -0,0 +1,698 @@
+//! Standard matrices converting between linear RGB and CIE XYZ for each of the RGB color spaces
+//! supported by the library.
+//!
+//! These are provided as data only, so that conversion crates can share a single canonical source
+//! for them; `cint` itself does not perform any conversions. Matrices are row-major and multiply
+//! a column vector of components, i.e. `xyz[i] = sum(MATRIX[i][j] * rgb[j])`. They are derived
+//! from the primaries and white point of each space, so the XYZ values are relative to that
+//! space's own white point, without any chromatic adaptation. See also
+//! [`Spaces::rgb_to_xyz_matrix`] and [`Spaces::xyz_to_rgb_matrix`].
+
+use crate::Spaces;
+
+type Matrix3 = [[f64; 3]; 3];
+
+/// The matrix converting linear sRGB/Rec.709 RGB to CIE XYZ, relative to the D65 white point.
+///
+/// Used by [`Spaces::LinearSrgb`], [`Spaces::Rec709`], and [`Spaces::ScRgb`] along with their
+/// encoded variants.
+pub const SRGB_TO_XYZ: [[f64; 3]; 3] = [
+    [0.4123907992659595, 0.35758433938387796, 0.1804807884018343],
+    [0.21263900587151036, 0.7151686787677559, 0.07219231536073371],
+    [0.01933081871559185, 0.11919477979462599, 0.9505321522496606],
+];
+
+/// The matrix converting CIE XYZ, relative to the D65 white point, to linear sRGB/Rec.709 RGB.
+///
+/// This is the inverse of [`SRGB_TO_XYZ`].
+pub const XYZ_TO_SRGB: [[f64; 3]; 3] = [
+    [3.2409699419045213, -1.5373831775700935, -0.4986107602930033],
+    [-0.9692436362808798, 1.8759675015077206, 0.04155505740717561],
+    [
+        0.05563007969699361,
+        -0.20397695888897657,
+        1.0569715142428786,
+    ],
+];
+
+/// The matrix converting linear Display P3 RGB to CIE XYZ, relative to the D65 white point.
+///
+/// Used by [`Spaces::DisplayP3`] and its encoded variants.
+pub const DISPLAY_P3_TO_XYZ: [[f64; 3]; 3] = [
+    [0.48657094864821626, 0.26566769316909294, 0.1982172852343625],
+    [0.22897456406974884, 0.6917385218365062, 0.079286914093745],
+    [0.0, 0.045113381858902575, 1.0439443689009757],
+];
+
+/// The matrix converting CIE XYZ, relative to the D65 white point, to linear Display P3 RGB.
+///
+/// This is the inverse of [`DISPLAY_P3_TO_XYZ`].
+pub const XYZ_TO_DISPLAY_P3: [[f64; 3]; 3] = [
+    [
+        2.4934969119414245,
+        -0.9313836179191236,
+        -0.40271078445071684,
+    ],
+    [-0.829488969561575, 1.7626640603183468, 0.02362468584194359],
+    [
+        0.035845830243784335,
+        -0.07617238926804171,
+        0.9568845240076873,
+    ],
+];
+
+/// The matrix converting linear DCI-P3 RGB to CIE XYZ, relative to the D60 white point.
+///
+/// Used by [`Spaces::DciP3`] and its encoded variants.
+pub const DCI_P3_TO_XYZ: [[f64; 3]; 3] = [
+    [0.5049495341917444, 0.2646814888952621, 0.18301505148283984],
+    [0.2376233102078797, 0.6891706691989844, 0.07320602059313594],
+    [0.0, 0.044945913208629416, 0.9638792711429565],
+];
+
+/// The matrix converting CIE XYZ, relative to the D60 white point, to linear DCI-P3 RGB.
+///
+/// This is the inverse of [`DCI_P3_TO_XYZ`].
+pub const XYZ_TO_DCI_P3: [[f64; 3]; 3] = [
+    [
+        2.4027414142222474,
+        -0.8974841639406845,
+        -0.38805336999607093,
+    ],
+    [-0.8325796487408842, 1.769231753574379, 0.023712711514772018],
+    [
+        0.038823381466856856,
+        -0.08249968561707081,
+        1.0363685997124843,
+    ],
+];
+
+/// The matrix converting linear BT.2020 RGB to CIE XYZ, relative to the D65 white point.
+///
+/// Used by [`Spaces::Bt2020`] and [`Spaces::Bt2100`] along with their encoded variants, and by
+/// [`Spaces::FujiFLog`], [`Spaces::FujiFLog2`], and [`Spaces::NikonNLog`].
+pub const BT2020_TO_XYZ: [[f64; 3]; 3] = [
+    [0.6369580483012913, 0.14461690358620838, 0.16888097516417205],
+    [0.26270021201126703, 0.677998071518871, 0.059301716469861945],
+    [0.0, 0.028072693049087508, 1.0609850577107909],
+];
+
+/// The matrix converting CIE XYZ, relative to the D65 white point, to linear BT.2020 RGB.
+///
+/// This is the inverse of [`BT2020_TO_XYZ`].
+pub const XYZ_TO_BT2020: [[f64; 3]; 3] = [
+    [1.7166511879712676, -0.3556707837763924, -0.2533662813736598],
+    [-0.666684351832489, 1.616481236634939, 0.01576854581391113],
+    [
+        0.017639857445310915,
+        -0.042770613257808655,
+        0.942103121235474,
+    ],
+];
+
+/// The matrix converting linear ACES AP0 RGB to CIE XYZ, relative to the ACES white point.
+///
+/// Used by [`Spaces::Aces2065`].
+pub const AP0_TO_XYZ: [[f64; 3]; 3] = [
+    [0.9525523959381859, 0.0, 0.00009367863166047],
+    [0.3439664497650751, 0.7281660966134856, -0.07213254637856079],
+    [0.0, 0.0, 1.0088251843515859],
+];
+
+/// The matrix converting CIE XYZ, relative to the ACES white point, to linear ACES AP0 RGB.
+///
+/// This is the inverse of [`AP0_TO_XYZ`].
+pub const XYZ_TO_AP0: [[f64; 3]; 3] = [
+    [1.0498110174979742, 0.0, -0.00009748454057925],
+    [-0.4959030230773199, 1.3733130458157066, 0.09824003605730999],
+    [0.0, 0.0, 0.991252018200499],
+];
+
+/// The matrix converting linear ACES AP1 RGB to CIE XYZ, relative to the ACES white point.
+///
+/// Used by [`Spaces::AcesCg`], [`Spaces::AcesCc`], and [`Spaces::AcesCct`].
+pub const AP1_TO_XYZ: [[f64; 3]; 3] = [
+    [0.6624541811085054, 0.1340042064564331, 0.1561876870049078],
+    [0.27222871678091454, 0.6740817658111484, 0.05368951740793705],
+    [
+        -0.005574649490394155,
+        0.0040607335289828215,
+        1.0103391003129971,
+    ],
+];
+
+/// The matrix converting CIE XYZ, relative to the ACES white point, to linear ACES AP1 RGB.
+///
+/// This is the inverse of [`AP1_TO_XYZ`].
+pub const XYZ_TO_AP1: [[f64; 3]; 3] = [
+    [1.6410233796943254, -0.3248032941847899, -0.2364246952376122],
+    [-0.6636628587229829, 1.615331591657338, 0.01675634768553013],
+    [
+        0.011721894328375445,
+        -0.008284441996237417,
+        0.9883948585390215,
+    ],
+];
+
+/// The matrix converting linear ProPhoto RGB (ROMM RGB) RGB to CIE XYZ, relative to the D50 white point.
+///
+/// Used by [`Spaces::ProPhotoRgb`] and its encoded variants.
+pub const PROPHOTO_RGB_TO_XYZ: [[f64; 3]; 3] = [
+    [
+        0.7977604896723025,
+        0.13518583717574034,
+        0.031349349581524806,
+    ],
+    [0.28807112822929337, 0.7118432178101014, 0.00008565396060526],
+    [0.0, 0.0, 0.8251046025104602],
+];
+
+/// The matrix converting CIE XYZ, relative to the D50 white point, to linear ProPhoto RGB (ROMM RGB) RGB.
+///
+/// This is the inverse of [`PROPHOTO_RGB_TO_XYZ`].
+pub const XYZ_TO_PROPHOTO_RGB: [[f64; 3]; 3] = [
+    [
+        1.3457989731028284,
+        -0.2555801000799755,
+        -0.05110628506753402,
+    ],
+    [
+        -0.5446224939028348,
+        1.5082327413132783,
+        0.020536032391479733,
+    ],
+    [0.0, 0.0, 1.2119675456389452],
+];
+
+/// The matrix converting linear Adobe RGB (1998) RGB to CIE XYZ, relative to the D65 white point.
+///
+/// Used by [`Spaces::AdobeRgb1998`] and its encoded variants.
+pub const ADOBE_RGB_1998_TO_XYZ: [[f64; 3]; 3] = [
+    [0.5766690429101308, 0.18555823790654627, 0.18822864623499472],
+    [0.29734497525053616, 0.627363566255466, 0.07529145849399789],
+    [
+        0.027031361386412378,
+        0.07068885253582714,
+        0.9913375368376389,
+    ],
+];
+
+/// The matrix converting CIE XYZ, relative to the D65 white point, to linear Adobe RGB (1998) RGB.
+///
+/// This is the inverse of [`ADOBE_RGB_1998_TO_XYZ`].
+pub const XYZ_TO_ADOBE_RGB_1998: [[f64; 3]; 3] = [
+    [2.041587903810746, -0.5650069742788596, -0.3447313507783295],
+    [-0.9692436362808798, 1.8759675015077206, 0.04155505740717561],
+    [
+        0.013444280632031024,
+        -0.11836239223101824,
+        1.0151749943912054,
+    ],
+];
+
+/// The matrix converting linear Adobe Wide Gamut RGB RGB to CIE XYZ, relative to the D50 white point.
+///
+/// Used by [`Spaces::WideGamutRgb`] and its encoded variants.
+pub const WIDE_GAMUT_RGB_TO_XYZ: [[f64; 3]; 3] = [
+    [0.7165007167793861, 0.10102057439747675, 0.14677438525270484],
+    [
+        0.25872824304011316,
+        0.7246823149485658,
+        0.016589442011321044,
+    ],
+    [0.0, 0.05121181896538752, 0.7738927835450727],
+];
+
+/// The matrix converting CIE XYZ, relative to the D50 white point, to linear Adobe Wide Gamut RGB RGB.
+///
+/// This is the inverse of [`WIDE_GAMUT_RGB_TO_XYZ`].
+pub const XYZ_TO_WIDE_GAMUT_RGB: [[f64; 3]; 3] = [
+    [
+        1.4623041819736535,
+        -0.18452563961567411,
+        -0.27338104768787297,
+    ],
+    [-0.5228682776866815, 1.4479884041326987, 0.0681261687448051],
+    [
+        0.034600446145748004,
+        -0.0958196297899776,
+        1.2876604565487555,
+    ],
+];
+
+/// The matrix converting linear BT.601 625-line (EBU) RGB to CIE XYZ, relative to the D65 white point.
+///
+/// Used by [`Spaces::Bt601_625`] and its encoded variants.
+pub const BT601_625_TO_XYZ: [[f64; 3]; 3] = [
+    [0.4305538133299025, 0.34154980353055325, 0.17835231019121597],
+    [0.22200430999823098, 0.7066547659252826, 0.07134092407648639],
+    [0.02018220999983918, 0.1295533737529685, 0.9393221670070707],
+];
+
+/// The matrix converting CIE XYZ, relative to the D65 white point, to linear BT.601 625-line (EBU) RGB.
+///
+/// This is the inverse of [`BT601_625_TO_XYZ`].
+pub const XYZ_TO_BT601_625: [[f64; 3]; 3] = [
+    [3.06336109008327, -1.3933901749073667, -0.4758237379975298],
+    [-0.9692436362808798, 1.8759675015077206, 0.04155505740717561],
+    [0.0678610475535668, -0.2287992696204957, 1.0690896180160279],
+];
+
+/// The matrix converting linear BT.601 525-line (SMPTE C) RGB to CIE XYZ, relative to the D65 white point.
+///
+/// Used by [`Spaces::Bt601_525`] and its encoded variants.
+pub const BT601_525_TO_XYZ: [[f64; 3]; 3] = [
+    [0.3935209036593899, 0.3652580767176035, 0.1916769466746783],
+    [0.21237636070506757, 0.7010598569257229, 0.08656378236920956],
+    [0.01873909065044714, 0.11193392673603979, 0.9583847333733915],
+];
+
+/// The matrix converting CIE XYZ, relative to the D65 white point, to linear BT.601 525-line (SMPTE C) RGB.
+///
+/// This is the inverse of [`BT601_525_TO_XYZ`].
+pub const XYZ_TO_BT601_525: [[f64; 3]; 3] = [
+    [3.5060032827246617, -1.7397907263028314, -0.5440582683627411],
+    [-1.069047559853815, 1.977778882728787, 0.035171419337195135],
+    [
+        0.056306591734127664,
+        -0.19697565482077187,
+        1.0499523282187335,
+    ],
+];
+
+/// The matrix converting linear ARRI Wide Gamut 3 RGB to CIE XYZ, relative to the D65 white point.
+///
+/// Used by [`Spaces::ArriLogC3`].
+pub const ARRI_WIDE_GAMUT_3_TO_XYZ: [[f64; 3]; 3] = [
+    [0.6380076192837255, 0.21470385633710226, 0.09774445143084397],
+    [
+        0.29195377899971653,
+        0.8238410415106909,
+        -0.11579482051040749,
+    ],
+    [
+        0.0027982790319461648,
+        -0.06703423568895953,
+        1.1532937074168919,
+    ],
+];
+
+/// The matrix converting CIE XYZ, relative to the D65 white point, to linear ARRI Wide Gamut 3 RGB.
+///
+/// This is the inverse of [`ARRI_WIDE_GAMUT_3_TO_XYZ`].
+pub const XYZ_TO_ARRI_WIDE_GAMUT_3: [[f64; 3]; 3] = [
+    [
+        1.789065550974014,
+        -0.48253386377102503,
+        -0.20007579293582753,
+    ],
+    [-0.6398486599016595, 1.396399956860706, 0.19443229177805382],
+    [
+        -0.04153154585267011,
+        0.08233537355382704,
+        0.8788684802928296,
+    ],
+];
+
+/// The matrix converting linear ARRI Wide Gamut 4 RGB to CIE XYZ, relative to the D65 white point.
+///
+/// Used by [`Spaces::ArriLogC4`].
+pub const ARRI_WIDE_GAMUT_4_TO_XYZ: [[f64; 3]; 3] = [
+    [0.7048583204072322, 0.129760295170463, 0.11583731147397655],
+    [0.2545241764040271, 0.7814777327120019, -0.03600190911602904],
+    [0.0, 0.0, 1.0890577507598784],
+];
+
+/// The matrix converting CIE XYZ, relative to the D65 white point, to linear ARRI Wide Gamut 4 RGB.
+///
+/// This is the inverse of [`ARRI_WIDE_GAMUT_4_TO_XYZ`].
+pub const XYZ_TO_ARRI_WIDE_GAMUT_4: [[f64; 3]; 3] = [
+    [1.5092154722421087, -0.25059734520438, -0.16881147529407306],
+    [-0.491545451660619, 1.3612455459293509, 0.09728294201372904],
+    [0.0, 0.0, 0.9182249511582473],
+];
+
+/// The matrix converting linear Sony S-Gamut3 RGB to CIE XYZ, relative to the D65 white point.
+///
+/// Used by [`Spaces::SonySLog3`].
+pub const S_GAMUT3_TO_XYZ: [[f64; 3]; 3] = [
+    [0.7064827131923189, 0.12880104979055762, 0.11517216406879523],
+    [
+        0.27097967081349217,
+        0.7866064112209055,
+        -0.057586082034397613,
+    ],
+    [
+        -0.009677845386196149,
+        0.004600037492519915,
+        1.0941355586535546,
+    ],
+];
+
+/// The matrix converting CIE XYZ, relative to the D65 white point, to linear Sony S-Gamut3 RGB.
+///
+/// This is the inverse of [`S_GAMUT3_TO_XYZ`].
+pub const XYZ_TO_S_GAMUT3: [[f64; 3]; 3] = [
+    [
+        1.5073998990602453,
+        -0.24582213738520922,
+        -0.17161168081608946,
+    ],
+    [-0.5181517270834446, 1.3553912409018918, 0.12587866816151477],
+    [
+        0.015511698157910325,
+        -0.007872771426879109,
+        0.9119163655748386,
+    ],
+];
+
+/// The matrix converting linear Sony S-Gamut3.Cine RGB to CIE XYZ, relative to the D65 white point.
+///
+/// Used by [`Spaces::SonySLog3Cine`].
+pub const S_GAMUT3_CINE_TO_XYZ: [[f64; 3]; 3] = [
+    [0.5990839207583274, 0.2489255161154236, 0.10244649017792078],
+    [0.2150758201155875, 0.8850685017437283, -0.10014432185931582],
+    [
+        -0.032065849544505774,
+        -0.02765839067949151,
+        1.1487819909838757,
+    ],
+];
+
+/// The matrix converting CIE XYZ, relative to the D65 white point, to linear Sony S-Gamut3.Cine RGB.
+///
+/// This is the inverse of [`S_GAMUT3_CINE_TO_XYZ`].
+pub const XYZ_TO_S_GAMUT3_CINE: [[f64; 3]; 3] = [
+    [
+        1.8467789692899126,
+        -0.5259861229686582,
+        -0.21054521138785118,
+    ],
+    [-0.44415326290314705, 1.259442902808137, 0.14939997288846493],
+    [0.04085542119620537, 0.01564088931040842, 0.8682072486989177],
+];
+
+/// The matrix converting linear Panasonic V-Gamut RGB to CIE XYZ, relative to the D65 white point.
+///
+/// Used by [`Spaces::PanasonicVLog`].
+pub const V_GAMUT_TO_XYZ: [[f64; 3]; 3] = [
+    [0.6796444698784742, 0.1522114124397545, 0.11860004473344309],
+    [
+        0.26068555009037364,
+        0.7748944633296593,
+        -0.035580013420032926,
+    ],
+    [
+        -0.009310198217513345,
+        -0.0046124670436289246,
+        1.1029804160210206,
+    ],
+];
+
+/// The matrix converting CIE XYZ, relative to the D65 white point, to linear Panasonic V-Gamut RGB.
+///
+/// This is the inverse of [`V_GAMUT_TO_XYZ`].
+pub const XYZ_TO_V_GAMUT: [[f64; 3]; 3] = [
+    [
+        1.589011773872392,
+        -0.31320448446021987,
+        -0.18096485152800623,
+    ],
+    [-0.5340529104491584, 1.3960114333501839, 0.1024576710165821],
+    [
+        0.011179448842978015,
+        0.0031941282408508615,
+        0.9055353562812192,
+    ],
+];
+
+/// The matrix converting linear REDWideGamutRGB RGB to CIE XYZ, relative to the D65 white point.
+///
+/// Used by [`Spaces::RedLog3G10`].
+pub const RED_WIDE_GAMUT_RGB_TO_XYZ: [[f64; 3]; 3] = [
+    [0.7352752459058587, 0.06860941061396104, 0.14657127053185198],
+    [0.2866940994999349, 0.8429791340169753, -0.12967323351691032],
+    [
+        -0.07968085687836766,
+        -0.3473432169944296,
+        1.5160818246326757,
+    ],
+];
+
+/// The matrix converting CIE XYZ, relative to the D65 white point, to linear REDWideGamutRGB RGB.
+///
+/// This is the inverse of [`RED_WIDE_GAMUT_RGB_TO_XYZ`].
+pub const XYZ_TO_RED_WIDE_GAMUT_RGB: [[f64; 3]; 3] = [
+    [
+        1.4128064803693583,
+        -0.17752320098966518,
+        -0.1517707320287427,
+    ],
+    [-0.4862032768604906, 1.2906964267981604, 0.1574006147297812],
+    [
+        -0.037139010852832416,
+        0.2863759997794539,
+        0.6876797788619603,
+    ],
+];
+
+/// The matrix converting linear Canon Cinema Gamut RGB to CIE XYZ, relative to the D65 white point.
+///
+/// Used by [`Spaces::CanonLog3`].
+pub const CINEMA_GAMUT_TO_XYZ: [[f64; 3]; 3] = [
+    [0.7160496465515206, 0.1296834778757396, 0.10472280262441158],
+    [0.2612613575255548, 0.8696421457549597, -0.13090350328051448],
+    [
+        -0.009676346575020547,
+        -0.23648163612634868,
+        1.3352157334612476,
+    ],
+];
+
+/// The matrix converting CIE XYZ, relative to the D65 white point, to linear Canon Cinema Gamut RGB.
+///
+/// This is the inverse of [`CINEMA_GAMUT_TO_XYZ`].
+pub const XYZ_TO_CINEMA_GAMUT: [[f64; 3]; 3] = [
+    [1.489818274932183, -0.2608959021837416, -0.14242652177740078],
+    [-0.4581665744692729, 1.261627783050228, 0.15962363162996535],
+    [-0.0703496677225017, 0.22155766722563816, 0.7761816036271039],
+];
+
+/// The matrix converting linear Blackmagic Wide Gamut Generation 5 RGB to CIE XYZ, relative to the D65 white point.
+///
+/// Used by [`Spaces::BlackmagicFilmGen5`].
+pub const BLACKMAGIC_WIDE_GAMUT_GEN5_TO_XYZ: [[f64; 3]; 3] = [
+    [0.6065303722131458, 0.22040809533759817, 0.12347900045836883],
+    [
+        0.26798940707297414,
+        0.8327308786223491,
+        -0.10072028569532324,
+    ],
+    [
+        -0.02944216601530972,
+        -0.08661060696630067,
+        1.2048607644426026,
+    ],
+];
+
+/// The matrix converting CIE XYZ, relative to the D65 white point, to linear Blackmagic Wide Gamut Generation 5 RGB.
+///
+/// This is the inverse of [`BLACKMAGIC_WIDE_GAMUT_GEN5_TO_XYZ`].
+pub const XYZ_TO_BLACKMAGIC_WIDE_GAMUT_GEN5: [[f64; 3]; 3] = [
+    [1.8663823403522928, -0.518397342870321, -0.23460980943156393],
+    [-0.6003424924985553, 1.3781489624976897, 0.17673183028613093],
+    [
+        0.0024519937381308446,
+        0.08639967419752272,
+        0.8369427073175152,
+    ],
+];
+
+/// The matrix converting linear DaVinci Wide Gamut RGB to CIE XYZ, relative to the D65 white point.
+///
+/// Used by [`Spaces::DaVinciIntermediate`].
+pub const DAVINCI_WIDE_GAMUT_TO_XYZ: [[f64; 3]; 3] = [
+    [0.7006223920936717, 0.14877481512319676, 0.10105871983480326],
+    [
+        0.27411851090664907,
+        0.8736318959404367,
+        -0.14775040684708576,
+    ],
+    [
+        -0.09896291288323113,
+        -0.13789532507554328,
+        1.325915988718653,
+    ],
+];
+
+/// The matrix converting CIE XYZ, relative to the D65 white point, to linear DaVinci Wide Gamut RGB.
+///
+/// This is the inverse of [`DAVINCI_WIDE_GAMUT_TO_XYZ`].
+pub const XYZ_TO_DAVINCI_WIDE_GAMUT: [[f64; 3]; 3] = [
+    [
+        1.5166720420240434,
+        -0.28147804787896913,
+        -0.14696363323677797,
+    ],
+    [-0.4649171012332759, 1.2514237756817124, 0.17488460886509075],
+    [0.06484904706715995, 0.10913934371056982, 0.7614146215498789],
+];
+
+/// The matrix converting linear FilmLight E-Gamut RGB to CIE XYZ, relative to the D65 white point.
+///
+/// Used by [`Spaces::FilmLightTLogEGamut`].
+pub const E_GAMUT_TO_XYZ: [[f64; 3]; 3] = [
+    [0.7053968500877708, 0.16404132830991897, 0.08101774865398198],
+    [
+        0.28013072409110595,
+        0.8202066415495948,
+        -0.10033736564070077,
+    ],
+    [
+        -0.10378151156916328,
+        -0.07290725702663065,
+        1.2657465193556723,
+    ],
+];
+
+/// The matrix converting CIE XYZ, relative to the D65 white point, to linear FilmLight E-Gamut RGB.
+///
+/// This is the inverse of [`E_GAMUT_TO_XYZ`].
+pub const XYZ_TO_E_GAMUT: [[f64; 3]; 3] = [
+    [
+        1.525052770404748,
+        -0.31591351093474296,
+        -0.12265826460517522,
+    ],
+    [-0.5091525599713287, 1.333327408732149, 0.1382843651413829],
+    [
+        0.09571534531370489,
+        0.050897443851516025,
+        0.7879557702853913,
+    ],
+];
+
+/// The matrix converting linear DJI D-Gamut RGB to CIE XYZ, relative to the D65 white point.
+///
+/// Used by [`Spaces::DjiDLog`].
+pub const D_GAMUT_TO_XYZ: [[f64; 3]; 3] = [
+    [0.6481719686338151, 0.19405814982075514, 0.10822580859710149],
+    [0.2830046623612432, 0.8131960563917359, -0.0962007187529791],
+    [
+        -0.018258365313628594,
+        -0.08316777849460935,
+        1.1904838945681164,
+    ],
+];
+
+/// The matrix converting CIE XYZ, relative to the D65 white point, to linear DJI D-Gamut RGB.
+///
+/// This is the inverse of [`D_GAMUT_TO_XYZ`].
+pub const XYZ_TO_D_GAMUT: [[f64; 3]; 3] = [
+    [
+        1.725775899752295,
+        -0.43144397493807374,
+        -0.19175287775025499,
+    ],
+    [-0.6024441152302866, 1.3905744120628332, 0.16713729629873986],
+    [
+        -0.015618936122160204,
+        0.09052917344591885,
+        0.848729956075055,
+    ],
+];
+
+/// The matrix converting linear GoPro Protune Native RGB to CIE XYZ, relative to the D65 white point.
+///
+/// Used by [`Spaces::GoProProtune`].
+pub const PROTUNE_NATIVE_TO_XYZ: [[f64; 3]; 3] = [
+    [0.5022634922544306, 0.2929635547578018, 0.15522888003943944],
+    [
+        0.13880763185792458,
+        0.9108330303414587,
+        -0.049640662199383276,
+    ],
+    [0.07804252823460633, -0.31482947867453087, 1.325844701199803],
+];
+
+/// The matrix converting CIE XYZ, relative to the D65 white point, to linear GoPro Protune Native RGB.
+///
+/// This is the inverse of [`PROTUNE_NATIVE_TO_XYZ`].
+pub const XYZ_TO_PROTUNE_NATIVE: [[f64; 3]; 3] = [
+    [2.266897951424983, -0.8316334039005573, -0.29654367716245844],
+    [-0.3573640344594764, 1.2433931194314178, 0.08839351739475806],
+    [
+        -0.21829342439808405,
+        0.34420289236730917,
+        0.7926809997779407,
+    ],
+];
+
+impl Spaces {
+    /// The matrix converting linear RGB with this space's primaries to CIE XYZ, or `None` if this
+    /// is not an RGB space. See the [`matrices`][crate::matrices] module for details.
+    pub fn rgb_to_xyz_matrix(&self) -> Option<[[f64; 3]; 3]> {
+        self.rgb_xyz_matrices().map(|(to_xyz, _)| to_xyz)
+    }
+
+    /// The matrix converting CIE XYZ to linear RGB with this space's primaries, or `None` if this
+    /// is not an RGB space. See the [`matrices`][crate::matrices] module for details.
+    pub fn xyz_to_rgb_matrix(&self) -> Option<[[f64; 3]; 3]> {
+        self.rgb_xyz_matrices().map(|(_, from_xyz)| from_xyz)
+    }
+
+    fn rgb_xyz_matrices(&self) -> Option<(Matrix3, Matrix3)> {
+        match *self {
+            Self::EncodedSrgb
+            | Self::LinearSrgb
+            | Self::EncodedRec709
+            | Self::Rec709
+            | Self::ScRgb
+            | Self::EncodedExtendedSrgb
+            | Self::EncodedBt1886 => Some((SRGB_TO_XYZ, XYZ_TO_SRGB)),
+            Self::DisplayP3 | Self::EncodedDisplayP3 | Self::EncodedDisplayP3PQ => {
+                Some((DISPLAY_P3_TO_XYZ, XYZ_TO_DISPLAY_P3))
+            }
+            Self::DciP3 | Self::EncodedDciP3 => Some((DCI_P3_TO_XYZ, XYZ_TO_DCI_P3)),
+            Self::Bt2020
+            | Self::EncodedBt2020
+            | Self::Bt2100
+            | Self::EncodedBt2100PQ
+            | Self::EncodedBt2100HLG
+            | Self::FujiFLog
+            | Self::FujiFLog2
+            | Self::NikonNLog => Some((BT2020_TO_XYZ, XYZ_TO_BT2020)),
+            Self::Aces2065 => Some((AP0_TO_XYZ, XYZ_TO_AP0)),
+            Self::AcesCg | Self::AcesCc | Self::AcesCct => Some((AP1_TO_XYZ, XYZ_TO_AP1)),
+            Self::ProPhotoRgb | Self::EncodedProPhotoRgb => {
+                Some((PROPHOTO_RGB_TO_XYZ, XYZ_TO_PROPHOTO_RGB))
+            }
+            Self::AdobeRgb1998 | Self::EncodedAdobeRgb1998 => {
+                Some((ADOBE_RGB_1998_TO_XYZ, XYZ_TO_ADOBE_RGB_1998))
+            }
+            Self::WideGamutRgb | Self::EncodedWideGamutRgb => {
+                Some((WIDE_GAMUT_RGB_TO_XYZ, XYZ_TO_WIDE_GAMUT_RGB))
+            }
+            Self::Bt601_625 | Self::EncodedBt601_625 => Some((BT601_625_TO_XYZ, XYZ_TO_BT601_625)),
+            Self::Bt601_525 | Self::EncodedBt601_525 => Some((BT601_525_TO_XYZ, XYZ_TO_BT601_525)),
+            Self::ArriLogC3 => Some((ARRI_WIDE_GAMUT_3_TO_XYZ, XYZ_TO_ARRI_WIDE_GAMUT_3)),
+            Self::ArriLogC4 => Some((ARRI_WIDE_GAMUT_4_TO_XYZ, XYZ_TO_ARRI_WIDE_GAMUT_4)),
+            Self::SonySLog3 => Some((S_GAMUT3_TO_XYZ, XYZ_TO_S_GAMUT3)),
+            Self::SonySLog3Cine => Some((S_GAMUT3_CINE_TO_XYZ, XYZ_TO_S_GAMUT3_CINE)),
+            Self::PanasonicVLog => Some((V_GAMUT_TO_XYZ, XYZ_TO_V_GAMUT)),
+            Self::RedLog3G10 => Some((RED_WIDE_GAMUT_RGB_TO_XYZ, XYZ_TO_RED_WIDE_GAMUT_RGB)),
+            Self::CanonLog3 => Some((CINEMA_GAMUT_TO_XYZ, XYZ_TO_CINEMA_GAMUT)),
+            Self::BlackmagicFilmGen5 => Some((
+                BLACKMAGIC_WIDE_GAMUT_GEN5_TO_XYZ,
+                XYZ_TO_BLACKMAGIC_WIDE_GAMUT_GEN5,
+            )),
+            Self::DaVinciIntermediate => {
+                Some((DAVINCI_WIDE_GAMUT_TO_XYZ, XYZ_TO_DAVINCI_WIDE_GAMUT))
+            }
+            Self::FilmLightTLogEGamut => Some((E_GAMUT_TO_XYZ, XYZ_TO_E_GAMUT)),
+            Self::DjiDLog => Some((D_GAMUT_TO_XYZ, XYZ_TO_D_GAMUT)),
+            Self::GoProProtune => Some((PROTUNE_NATIVE_TO_XYZ, XYZ_TO_PROTUNE_NATIVE)),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,61 @@
+/// The matrix coefficients used to derive luma and chroma signals from RGB, as defined in
+/// ITU-T H.273 (aka "MatrixCoefficients" or "colour_matrix" in video bitstreams).
+///
+/// The discriminant of each variant is its H.273 code point, so metadata can be passed through
+/// unchanged. See [`YPrimeCbCr`][crate::YPrimeCbCr] for tracking the derivation in the type
+/// of a color itself.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum MatrixCoefficients {
+    /// The identity matrix, i.e. the "YCbCr" components actually hold G, B, and R (aka GBR).
+    Identity = 0,
+    /// The ITU-R BT.709 matrix, with Kr = 0.2126 and Kb = 0.0722.
+    Bt709 = 1,
+    /// The ITU-R BT.601 matrix, with Kr = 0.299 and Kb = 0.114.
+    ///
+    /// H.273 code points 5 (BT.470 System B, G) and 6 (SMPTE 170M) both describe this matrix;
+    /// this variant uses 6.
+    Bt601 = 6,
+    /// The YCoCg matrix.
+    YCoCg = 8,
+    /// The ITU-R BT.2020 non-constant luminance matrix, with Kr = 0.2627 and Kb = 0.0593.
+    Bt2020Ncl = 9,
+    /// The ITU-R BT.2020 constant luminance system.
+    Bt2020Cl = 10,
+    /// The ICtCp matrix defined in ITU-R BT.2100.
+    ICtCp = 14,
+}
+
+impl MatrixCoefficients {
+    /// The H.273 code point of this matrix.
+    pub const fn h273_code_point(self) -> u8 {
+        self as u8
+    }
+
+    /// The matrix coefficients corresponding to an H.273 code point, or `None` if the code point is
+    /// unspecified, reserved, or describes a matrix not represented here.
+    pub const fn from_h273_code_point(code_point: u8) -> Option<MatrixCoefficients> {
+        match code_point {
+            0 => Some(Self::Identity),
+            1 => Some(Self::Bt709),
+            5 | 6 => Some(Self::Bt601),
+            8 => Some(Self::YCoCg),
+            9 => Some(Self::Bt2020Ncl),
+            10 => Some(Self::Bt2020Cl),
+            14 => Some(Self::ICtCp),
+            _ => None,
+        }
+    }
+
+    /// The `[Kr, Kg, Kb]` luma coefficients of this matrix, or `None` if it is not a
+    /// Kr/Kb-based luma/chroma matrix (i.e. [`Identity`][Self::Identity], [`YCoCg`][Self::YCoCg],
+    /// or [`ICtCp`][Self::ICtCp]).
+    pub const fn luma_coefficients(self) -> Option<[f32; 3]> {
+        match self {
+            Self::Bt709 => Some([0.2126, 0.7152, 0.0722]),
+            Self::Bt601 => Some([0.299, 0.587, 0.114]),
+            Self::Bt2020Ncl | Self::Bt2020Cl => Some([0.2627, 0.6780, 0.0593]),
+            Self::Identity | Self::YCoCg | Self::ICtCp => None,
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use core::marker::PhantomData;
+
+use crate::ColorType;
+
+/// A CIE standard colorimetric observer, i.e. the set of color matching functions that tristimulus
+/// values were computed with.
+///
+/// Values computed with different observers are not interchangeable, even if they share a space.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum StandardObserver {
+    /// The CIE 1931 2° standard observer, which is assumed by all of the spaces in
+    /// [`Spaces`][crate::Spaces].
+    Cie1931TwoDegree,
+    /// The CIE 1964 10° supplementary standard observer, used for colorimetry of larger fields,
+    /// i.e. in textile, paint, and print measurement.
+    Cie1964TenDegree,
+}
+
+/// A type-level marker for a [`StandardObserver`], used as the `O` parameter of [`Observed`].
+pub trait Observer {
+    /// The observer this marker stands for.
+    const OBSERVER: StandardObserver;
+}
+
+/// The type-level marker for [`StandardObserver::Cie1931TwoDegree`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum Cie1931TwoDegree {}
+
+impl Observer for Cie1931TwoDegree {
+    const OBSERVER: StandardObserver = StandardObserver::Cie1931TwoDegree;
+}
+
+/// The type-level marker for [`StandardObserver::Cie1964TenDegree`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum Cie1964TenDegree {}
+
+impl Observer for Cie1964TenDegree {
+    const OBSERVER: StandardObserver = StandardObserver::Cie1964TenDegree;
+}
+
+/// A color whose values were computed with the standard observer `O`, i.e.
+/// `Observed<CieXYZ<f32>, Cie1964TenDegree>` for 10° tristimulus values.
+///
+/// Colors that are not wrapped in [`Observed`] are assumed to use the CIE 1931 2° observer. This
+/// is mostly useful for spaces that are measured directly, such as [`CieXYZ`][crate::CieXYZ] or
+/// [`CieLab`][crate::CieLab], so that measurement pipelines can't mix up 2° and 10° data.
+///
+/// This has the same layout as `ColorTy` and passes through its [`ColorType`], array, and
+/// `bytemuck` impls.
+#[repr(transparent)]
+pub struct Observed<ColorTy: ColorType, O: Observer> {
+    /// The contained color.
+    pub color: ColorTy,
+    _observer: PhantomData<fn() -> O>,
+}
+
+impl<ColorTy: ColorType, O: Observer> Observed<ColorTy, O> {
+    /// The [`StandardObserver`] of the contained color.
+    pub const OBSERVER: StandardObserver = O::OBSERVER;
+
+    /// Mark `color` as computed with the observer `O`.
+    pub const fn new(color: ColorTy) -> Self {
+        Self {
+            color,
+            _observer: PhantomData,
+        }
+    }
+
+    /// Remove the observer, returning the contained color.
+    pub fn into_inner(self) -> ColorTy {
+        self.color
+    }
+}
+
+impl_transparent_wrapper! {
+    Observed<ColorTy, O: Observer> {
+        marker: _observer,
+        debug: "observer" => O::OBSERVER,
+    }
+}
@@ -0,0 +1,91 @@
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+
+use crate::{ColorType, Spaces};
+
+mod sealed {
+    pub trait Sealed {}
+
+    impl Sealed for f32 {}
+    impl Sealed for f64 {}
+}
+
+/// A floating point component type which can be totally ordered and hashed by [`OrderedColor`].
+///
+/// This trait is sealed and cannot be implemented outside of `cint`.
+pub trait TotalOrdComponent: Copy + sealed::Sealed {
+    /// Compare `self` and `other` using the IEEE 754 `totalOrder` predicate.
+    fn total_cmp(&self, other: &Self) -> Ordering;
+
+    /// Feed the bit representation of `self` into `state`.
+    fn hash_bits<H: Hasher>(&self, state: &mut H);
+}
+
+impl TotalOrdComponent for f32 {
+    fn total_cmp(&self, other: &Self) -> Ordering {
+        f32::total_cmp(self, other)
+    }
+
+    fn hash_bits<H: Hasher>(&self, state: &mut H) {
+        self.to_bits().hash(state)
+    }
+}
+
+impl TotalOrdComponent for f64 {
+    fn total_cmp(&self, other: &Self) -> Ordering {
+        f64::total_cmp(self, other)
+    }
+
+    fn hash_bits<H: Hasher>(&self, state: &mut H) {
+        self.to_bits().hash(state)
+    }
+}
+
+/// A wrapper around a color with floating point components which implements [`Eq`], [`Ord`],
+/// and [`Hash`], so that it can be used as a key in maps and sets.
+///
+/// Components are compared in order using the IEEE 754 `totalOrder` predicate
+/// (see [`f32::total_cmp`]) and hashed by their bit representations. This means that i.e. `-0.0`
+/// and `0.0` are considered different, while NaNs with the same bit representation are considered equal.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+pub struct OrderedColor<ColorTy: ColorType>(pub ColorTy);
+
+impl<ColorTy: ColorType> OrderedColor<ColorTy> {
+    /// Get the contained color.
+    pub fn into_inner(self) -> ColorTy {
+        self.0
+    }
+}
+
+impl<ColorTy: ColorType> From<ColorTy> for OrderedColor<ColorTy> {
+    fn from(color: ColorTy) -> Self {
+        Self(color)
+    }
+}
+
+impl<ColorTy: ColorType> AsRef<ColorTy> for OrderedColor<ColorTy> {
+    fn as_ref(&self) -> &ColorTy {
+        &self.0
+    }
+}
+
+impl<BaseColorTy: ColorType> ColorType for OrderedColor<BaseColorTy> {
+    type ComponentTy = BaseColorTy::ComponentTy;
+    const SPACE: Spaces = BaseColorTy::SPACE;
+    const NUM_COMPONENTS: usize = BaseColorTy::NUM_COMPONENTS;
+}
+
+pub(crate) fn total_cmp_components<T: TotalOrdComponent>(a: &[T], b: &[T]) -> Ordering {
+    a.iter()
+        .zip(b)
+        .map(|(a, b)| a.total_cmp(b))
+        .find(|ordering| *ordering != Ordering::Equal)
+        .unwrap_or(Ordering::Equal)
+}
+
+pub(crate) fn hash_components<T: TotalOrdComponent, H: Hasher>(components: &[T], state: &mut H) {
+    for component in components {
+        component.hash_bits(state);
+    }
+}
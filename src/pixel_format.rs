@@ -0,0 +1,155 @@
+use crate::{ColorDescriptor, MatrixCoefficients, SpaceRef};
+
+/// The numeric type of the components of a pixel format.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum ComponentType {
+    /// Unsigned integer code values.
+    Unsigned,
+    /// Signed integer code values.
+    Signed,
+    /// IEEE 754 floating point values.
+    Float,
+}
+
+/// The chroma subsampling of a pixel format, i.e. how many luma samples share each chroma sample.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum ChromaSubsampling {
+    /// No subsampling (4:4:4), or no chroma components at all, i.e. RGB formats.
+    None,
+    /// Chroma is halved horizontally (4:2:2).
+    Yuv422,
+    /// Chroma is halved horizontally and vertically (4:2:0).
+    Yuv420,
+    /// Chroma is quartered horizontally (4:1:1).
+    Yuv411,
+    /// Chroma is halved vertically (4:4:0).
+    Yuv440,
+    /// Chroma is quartered horizontally and halved vertically (4:1:0).
+    Yuv410,
+}
+
+impl ChromaSubsampling {
+    /// The horizontal and vertical subsampling factors, i.e. `(2, 2)` for 4:2:0.
+    pub const fn factors(self) -> (u8, u8) {
+        match self {
+            Self::None => (1, 1),
+            Self::Yuv422 => (2, 1),
+            Self::Yuv420 => (2, 2),
+            Self::Yuv411 => (4, 1),
+            Self::Yuv440 => (1, 2),
+            Self::Yuv410 => (4, 2),
+        }
+    }
+
+    /// The subsampling with the given horizontal and vertical factors, or `None` if it is not
+    /// one of the variants of [`ChromaSubsampling`].
+    pub const fn from_factors(horizontal: u8, vertical: u8) -> Option<Self> {
+        match (horizontal, vertical) {
+            (1, 1) => Some(Self::None),
+            (2, 1) => Some(Self::Yuv422),
+            (2, 2) => Some(Self::Yuv420),
+            (4, 1) => Some(Self::Yuv411),
+            (1, 2) => Some(Self::Yuv440),
+            (4, 2) => Some(Self::Yuv410),
+            _ => None,
+        }
+    }
+}
+
+/// How the components of a pixel format are split into planes.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum PlaneLayout {
+    /// All components are interleaved in a single plane, i.e. RGBA or YUYV.
+    Packed,
+    /// Luma is in one plane, and the chroma components are interleaved in a second plane, i.e. NV12.
+    SemiPlanar,
+    /// Each component is in its own plane, i.e. I420.
+    Planar,
+}
+
+/// The order of the components of a pixel format in memory, relative to the memory order of the
+/// corresponding `cint` color type.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum ComponentOrder {
+    /// The memory order of the color type, followed by alpha, i.e. RGBA, Y'CbCr, or YUYV.
+    Native,
+    /// The color components in reverse, followed by alpha, as in [`Bgr`][crate::Bgr], i.e. BGRA,
+    /// Y'CrCb, or YVYU.
+    Reversed,
+    /// Alpha, followed by the memory order of the color type, as in [`AlphaFirst`][crate::AlphaFirst],
+    /// i.e. ARGB.
+    AlphaFirst,
+    /// Alpha, followed by the color components in reverse, i.e. ABGR.
+    ReversedAlphaFirst,
+    /// For packed subsampled formats, each chroma sample comes before the luma samples it is shared
+    /// by, i.e. UYVY.
+    ChromaFirst,
+}
+
+/// A runtime description of the memory format of a buffer of pixels, i.e. for mapping the pixel
+/// formats of image, video, and camera APIs.
+///
+/// This extends a [`ColorDescriptor`] with the numeric type, subsampling, plane layout, and order
+/// of the components. Packed formats with fewer significant bits than their storage, like 10-bit
+/// components in 16-bit words, are described by their significant bits.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct PixelFormat {
+    /// The color space, alpha state, and signal range of the pixels.
+    pub descriptor: ColorDescriptor,
+    /// The numeric type of the components.
+    pub component_type: ComponentType,
+    /// The number of significant bits of each component.
+    pub bits_per_component: u8,
+    /// The chroma subsampling of the pixels.
+    pub chroma_subsampling: ChromaSubsampling,
+    /// How the components are split into planes.
+    pub layout: PlaneLayout,
+    /// The order of the components in memory.
+    pub order: ComponentOrder,
+    /// Whether each pixel has an unused padding component in place of alpha, i.e. RGBX or XRGB,
+    /// which is placed by [`order`][Self::order] as alpha would be. Only used by packed formats
+    /// without alpha.
+    pub padded: bool,
+    /// The matrix used to derive the luma and chroma components, for formats with luma and chroma
+    /// components whose matrix is known.
+    pub matrix_coefficients: Option<MatrixCoefficients>,
+}
+
+impl PixelFormat {
+    /// Create a new packed [`PixelFormat`] without subsampling, in native component order.
+    pub fn new(
+        descriptor: ColorDescriptor,
+        component_type: ComponentType,
+        bits_per_component: u8,
+    ) -> Self {
+        Self {
+            descriptor,
+            component_type,
+            bits_per_component,
+            chroma_subsampling: ChromaSubsampling::None,
+            layout: PlaneLayout::Packed,
+            order: ComponentOrder::Native,
+            padded: false,
+            matrix_coefficients: None,
+        }
+    }
+
+    /// Whether `self` and `other` have the same memory layout, i.e. everything but their color space
+    /// metadata matches, and their spaces have the same components.
+    pub(crate) fn same_memory_layout(&self, other: &PixelFormat) -> bool {
+        let same_components = match (self.descriptor.space, other.descriptor.space) {
+            (SpaceRef::Builtin(space), SpaceRef::Builtin(other_space)) => {
+                space.component_names() == other_space.component_names()
+            }
+            (space, other_space) => space == other_space,
+        };
+        same_components
+            && self.descriptor.alpha_state == other.descriptor.alpha_state
+            && self.component_type == other.component_type
+            && self.bits_per_component == other.bits_per_component
+            && self.chroma_subsampling == other.chroma_subsampling
+            && self.layout == other.layout
+            && self.order == other.order
+            && self.padded == other.padded
+    }
+}
@@ -0,0 +1,56 @@
+use alloc::vec::Vec;
+
+use crate::{CustomColorSpace, CustomSpaceId, Error};
+
+/// A registry of [`CustomColorSpace`]s, handing out a [`CustomSpaceId`] for each.
+///
+/// An application should create a single registry and share it with any plugins that
+/// define their own spaces, so that every space gets a distinct id.
+///
+/// Requires the `alloc` feature.
+#[derive(Clone, Debug, Default)]
+pub struct ColorSpaceRegistry {
+    spaces: Vec<CustomColorSpace>,
+}
+
+impl ColorSpaceRegistry {
+    /// Create a new, empty registry.
+    pub fn new() -> Self {
+        Self { spaces: Vec::new() }
+    }
+
+    /// Register a custom space, returning the id it can be referred to by.
+    ///
+    /// Fails with [`Error::AlreadyRegistered`] if a space with the same
+    /// [`id`][CustomColorSpace::id] has already been registered.
+    pub fn register(&mut self, space: CustomColorSpace) -> Result<CustomSpaceId, Error> {
+        if self.find(space.id).is_some() {
+            return Err(Error::AlreadyRegistered);
+        }
+        let index = <u32 as core::convert::TryFrom<usize>>::try_from(self.spaces.len())
+            .map_err(|_| Error::OutOfRange)?;
+        self.spaces.push(space);
+        Ok(CustomSpaceId(index))
+    }
+
+    /// Get the custom space registered under `id`.
+    pub fn get(&self, id: CustomSpaceId) -> Option<&CustomColorSpace> {
+        self.spaces.get(id.0 as usize)
+    }
+
+    /// Find the id of the custom space registered with the given unique identifier string.
+    pub fn find(&self, id: &str) -> Option<CustomSpaceId> {
+        self.spaces
+            .iter()
+            .position(|space| space.id == id)
+            .map(|index| CustomSpaceId(index as u32))
+    }
+
+    /// Iterate over all registered spaces along with their ids.
+    pub fn iter(&self) -> impl Iterator<Item = (CustomSpaceId, &CustomColorSpace)> {
+        self.spaces
+            .iter()
+            .enumerate()
+            .map(|(index, space)| (CustomSpaceId(index as u32), space))
+    }
+}
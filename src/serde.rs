@@ -0,0 +1,207 @@
+//! Helpers for serializing `cint` colors with [`serde`](https://docs.rs/serde/).
+//!
+//! With the `serde` feature, the color type of every space (e.g. [`EncodedSrgb`][crate::EncodedSrgb]
+//! or [`Oklab`][crate::Oklab]) and its [`Alpha`][crate::Alpha], [`PremultipliedAlpha`][crate::PremultipliedAlpha],
+//! [`AlphaFirst`][crate::AlphaFirst], and [`PremultipliedAlphaFirst`][crate::PremultipliedAlphaFirst]
+//! forms implement `Serialize` and `Deserialize` as a tuple of their components in memory order,
+//! e.g. `[34, 177, 76]` for an `EncodedSrgb<u8>`. The other wrappers, like [`Bgr`][crate::Bgr] or
+//! [`Tagged`][crate::Tagged], do not. The [`hex`] module can be used to write 8-bit sRGB colors as
+//! `"#RRGGBB"` strings instead.
+
+/// Serializes 8-bit sRGB colors as `"#RRGGBB"` or `"#RRGGBBAA"` hex strings in human-readable
+/// formats, and as tuples of components otherwise, for use with `#[serde(with = "cint::serde::hex")]`.
+///
+/// This works for the colors which implement [`HexColor`][hex::HexColor]:
+/// [`EncodedSrgb<u8>`][crate::EncodedSrgb] and its alpha forms. Hex strings are always written in
+/// `RRGGBBAA` order, including for [`AlphaFirst`][crate::AlphaFirst] colors. When deserializing a
+/// color with an alpha component, a `"#RRGGBB"` string is also accepted and treated as fully opaque.
+///
+/// ```rust
+/// use cint::{Alpha, AlphaFirst, EncodedSrgb};
+///
+/// fn to_json<C: cint::serde::hex::HexColor + serde::Serialize>(color: &C) -> String {
+///     let mut json = Vec::new();
+///     cint::serde::hex::serialize(color, &mut serde_json::Serializer::new(&mut json)).unwrap();
+///     String::from_utf8(json).unwrap()
+/// }
+///
+/// fn from_json<'de, C: cint::serde::hex::HexColor + serde::Deserialize<'de>>(json: &'de str) -> C {
+///     cint::serde::hex::deserialize(&mut serde_json::Deserializer::from_str(json)).unwrap()
+/// }
+///
+/// let color = EncodedSrgb { r: 0x11, g: 0x22, b: 0x33 };
+/// assert_eq!(to_json(&color), r##""#112233""##);
+/// assert_eq!(from_json::<EncodedSrgb<u8>>(r##""#112233""##), color);
+///
+/// let alpha = Alpha { color, alpha: 0x80 };
+/// assert_eq!(to_json(&alpha), r##""#11223380""##);
+/// assert_eq!(from_json::<Alpha<EncodedSrgb<u8>>>(r##""#11223380""##), alpha);
+///
+/// let alpha_first = AlphaFirst { alpha: 0x80, color };
+/// assert_eq!(to_json(&alpha_first), r##""#11223380""##);
+/// assert_eq!(from_json::<AlphaFirst<EncodedSrgb<u8>>>(r##""#11223380""##), alpha_first);
+/// assert_eq!(
+///     from_json::<AlphaFirst<EncodedSrgb<u8>>>(r##""#112233""##),
+///     AlphaFirst { alpha: 0xFF, color },
+/// );
+/// ```
+pub mod hex {
+    use core::fmt;
+
+    use ::serde::de::{self, Deserialize, Deserializer, Visitor};
+    use ::serde::ser::{Serialize, Serializer};
+
+    use crate::{Alpha, AlphaFirst, EncodedSrgb, PremultipliedAlpha, PremultipliedAlphaFirst};
+
+    mod private {
+        pub trait Sealed {}
+    }
+
+    /// A color which can be written as a hex string, i.e. [`EncodedSrgb<u8>`] and its alpha forms.
+    ///
+    /// This trait is sealed and cannot be implemented outside of `cint`.
+    pub trait HexColor: Sized + private::Sealed {
+        /// Whether the color has an alpha component, which is written as the last two digits.
+        const HAS_ALPHA: bool;
+
+        /// The red, green, blue, and alpha components of the color, with an alpha of 255 for
+        /// colors without an alpha component.
+        fn to_rgba(&self) -> [u8; 4];
+
+        /// Create a color from its red, green, blue, and alpha components, ignoring the alpha
+        /// component for colors without one.
+        fn from_rgba(rgba: [u8; 4]) -> Self;
+    }
+
+    impl private::Sealed for EncodedSrgb<u8> {}
+
+    impl HexColor for EncodedSrgb<u8> {
+        const HAS_ALPHA: bool = false;
+
+        fn to_rgba(&self) -> [u8; 4] {
+            [self.r, self.g, self.b, u8::MAX]
+        }
+
+        fn from_rgba([r, g, b, _]: [u8; 4]) -> Self {
+            EncodedSrgb { r, g, b }
+        }
+    }
+
+    macro_rules! impl_hex_color_alpha {
+        ($($alphaty:ident),+) => {
+            $(
+                impl private::Sealed for $alphaty<EncodedSrgb<u8>> {}
+
+                impl HexColor for $alphaty<EncodedSrgb<u8>> {
+                    const HAS_ALPHA: bool = true;
+
+                    fn to_rgba(&self) -> [u8; 4] {
+                        let [r, g, b, _] = self.color.to_rgba();
+                        [r, g, b, self.alpha]
+                    }
+
+                    fn from_rgba(rgba: [u8; 4]) -> Self {
+                        $alphaty {
+                            color: EncodedSrgb::from_rgba(rgba),
+                            alpha: rgba[3],
+                        }
+                    }
+                }
+            )+
+        };
+    }
+
+    impl_hex_color_alpha!(
+        Alpha,
+        PremultipliedAlpha,
+        AlphaFirst,
+        PremultipliedAlphaFirst
+    );
+
+    /// Serialize `color` as a hex string in human-readable formats, and as a tuple of its
+    /// components otherwise.
+    pub fn serialize<C, S>(color: &C, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        C: HexColor + Serialize,
+        S: Serializer,
+    {
+        if serializer.is_human_readable() {
+            serializer.collect_str(&HexString::<C>(color.to_rgba(), core::marker::PhantomData))
+        } else {
+            color.serialize(serializer)
+        }
+    }
+
+    /// Deserialize a color from a hex string in human-readable formats, and from a tuple of its
+    /// components otherwise.
+    pub fn deserialize<'de, C, D>(deserializer: D) -> Result<C, D::Error>
+    where
+        C: HexColor + Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            deserializer
+                .deserialize_str(HexVisitor::<C>(core::marker::PhantomData))
+                .map(C::from_rgba)
+        } else {
+            C::deserialize(deserializer)
+        }
+    }
+
+    /// Writes `#` followed by two uppercase hex digits per component of a `C`.
+    struct HexString<C>([u8; 4], core::marker::PhantomData<C>);
+
+    impl<C: HexColor> fmt::Display for HexString<C> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            let [r, g, b, a] = self.0;
+            write!(f, "#{:02X}{:02X}{:02X}", r, g, b)?;
+            if C::HAS_ALPHA {
+                write!(f, "{:02X}", a)?;
+            }
+            Ok(())
+        }
+    }
+
+    /// Accepts a `"#RRGGBB"` hex string, or a `"#RRGGBBAA"` one if `C` has an alpha component.
+    struct HexVisitor<C>(core::marker::PhantomData<C>);
+
+    impl<'de, C: HexColor> Visitor<'de> for HexVisitor<C> {
+        type Value = [u8; 4];
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            if C::HAS_ALPHA {
+                f.write_str("a \"#RRGGBBAA\" or \"#RRGGBB\" hex string")
+            } else {
+                f.write_str("a \"#RRGGBB\" hex string")
+            }
+        }
+
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<[u8; 4], E> {
+            let digits = v
+                .strip_prefix('#')
+                .ok_or_else(|| E::invalid_value(de::Unexpected::Str(v), &self))?
+                .as_bytes();
+            if !(digits.len() == 6 || (C::HAS_ALPHA && digits.len() == 8)) {
+                return Err(E::invalid_length(digits.len(), &self));
+            }
+
+            let mut rgba = [u8::MAX; 4];
+            for (component, pair) in rgba.iter_mut().zip(digits.chunks(2)) {
+                match (hex_value(pair[0]), hex_value(pair[1])) {
+                    (Some(hi), Some(lo)) => *component = (hi << 4) | lo,
+                    _ => return Err(E::invalid_value(de::Unexpected::Str(v), &self)),
+                }
+            }
+            Ok(rgba)
+        }
+    }
+
+    fn hex_value(digit: u8) -> Option<u8> {
+        match digit {
+            b'0'..=b'9' => Some(digit - b'0'),
+            b'a'..=b'f' => Some(digit - b'a' + 10),
+            b'A'..=b'F' => Some(digit - b'A' + 10),
+            _ => None,
+        }
+    }
+}
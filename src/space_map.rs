@@ -0,0 +1,131 @@
+use core::fmt;
+use core::ops::{Index, IndexMut};
+
+use crate::Spaces;
+
+/// A dense map with a value of type `T` for every one of the [`Spaces`], backed by an array indexed
+/// by [`Spaces::index`].
+///
+/// This is useful for building lookup tables keyed by space, like per-space settings, conversion
+/// paths, or capability flags, without hashing or matching on every space.
+///
+/// ```rust
+/// use cint::{SpaceMap, Spaces};
+///
+/// let mut is_linear = SpaceMap::splat(false);
+/// is_linear[Spaces::LinearSrgb] = true;
+///
+/// assert!(is_linear[Spaces::LinearSrgb]);
+/// assert!(!is_linear[Spaces::EncodedSrgb]);
+///
+/// let num_components = SpaceMap::from_fn(|space| space.num_components());
+/// assert_eq!(num_components[Spaces::Oklab], 3);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SpaceMap<T> {
+    values: [T; Spaces::COUNT],
+}
+
+impl<T> SpaceMap<T> {
+    /// Create a new [`SpaceMap`] by calling `f` with each space, in the order of [`Spaces::index`].
+    pub fn from_fn<F: FnMut(Spaces) -> T>(mut f: F) -> Self {
+        Self {
+            values: core::array::from_fn(|index| f(Spaces::from_index(index).unwrap())),
+        }
+    }
+
+    /// Convert `self` into an iterator over its values, in the order of [`Spaces::index`].
+    pub fn into_values(self) -> impl Iterator<Item = T> {
+        IntoIterator::into_iter(self.values)
+    }
+
+    /// Get a reference to the value for `space`.
+    pub fn get(&self, space: Spaces) -> &T {
+        &self.values[space.index()]
+    }
+
+    /// Get a mutable reference to the value for `space`.
+    pub fn get_mut(&mut self, space: Spaces) -> &mut T {
+        &mut self.values[space.index()]
+    }
+
+    /// Replace the value for `space`, returning the previous value.
+    pub fn replace(&mut self, space: Spaces, value: T) -> T {
+        core::mem::replace(self.get_mut(space), value)
+    }
+
+    /// Transform each value with `f`, which is also given the space the value belongs to.
+    pub fn map<U, F: FnMut(Spaces, T) -> U>(self, mut f: F) -> SpaceMap<U> {
+        let mut index = 0;
+        SpaceMap {
+            values: self.values.map(|value| {
+                let space = Spaces::from_index(index).unwrap();
+                index += 1;
+                f(space, value)
+            }),
+        }
+    }
+
+    /// Iterate over each space and a reference to its value, in the order of [`Spaces::index`].
+    pub fn iter(&self) -> impl Iterator<Item = (Spaces, &T)> + '_ {
+        self.values
+            .iter()
+            .enumerate()
+            .map(|(index, value)| (Spaces::from_index(index).unwrap(), value))
+    }
+
+    /// Iterate over each space and a mutable reference to its value, in the order of [`Spaces::index`].
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Spaces, &mut T)> + '_ {
+        self.values
+            .iter_mut()
+            .enumerate()
+            .map(|(index, value)| (Spaces::from_index(index).unwrap(), value))
+    }
+
+    /// The values of the map as a slice, indexed by [`Spaces::index`].
+    ///
+    /// The length of the slice is [`Spaces::COUNT`], which grows as spaces are added.
+    pub fn values(&self) -> &[T] {
+        &self.values
+    }
+
+    /// The values of the map as a mutable slice, indexed by [`Spaces::index`].
+    pub fn values_mut(&mut self) -> &mut [T] {
+        &mut self.values
+    }
+}
+
+impl<T: Copy> SpaceMap<T> {
+    /// Create a new [`SpaceMap`] with the same `value` for every space.
+    pub const fn splat(value: T) -> Self {
+        Self {
+            values: [value; Spaces::COUNT],
+        }
+    }
+}
+
+impl<T: Default> Default for SpaceMap<T> {
+    fn default() -> Self {
+        Self::from_fn(|_| T::default())
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for SpaceMap<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<T> Index<Spaces> for SpaceMap<T> {
+    type Output = T;
+
+    fn index(&self, space: Spaces) -> &T {
+        self.get(space)
+    }
+}
+
+impl<T> IndexMut<Spaces> for SpaceMap<T> {
+    fn index_mut(&mut self, space: Spaces) -> &mut T {
+        self.get_mut(space)
+    }
+}
@@ -0,0 +1,305 @@
+use core::fmt;
+use core::iter::FromIterator;
+use core::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, Sub, SubAssign};
+
+use crate::Spaces;
+
+const NUM_WORDS: usize = Spaces::COUNT.div_ceil(64);
+
+/// A set of [`Spaces`], stored as a bitset.
+///
+/// This is useful for libraries and devices to declare which color spaces they can produce or
+/// consume, i.e. for capability queries and format negotiation. All set operations are cheap and
+/// most are available in `const` contexts.
+///
+/// ```rust
+/// use cint::{SpaceSet, Spaces};
+///
+/// const SRGB: SpaceSet = SpaceSet::from_spaces(&[Spaces::EncodedSrgb, Spaces::LinearSrgb]);
+/// const HDR: SpaceSet = SpaceSet::from_spaces(&[Spaces::LinearSrgb, Spaces::EncodedBt2100PQ]);
+///
+/// let common = SRGB.intersection(HDR);
+/// assert!(common.contains(Spaces::LinearSrgb));
+/// assert_eq!(common.len(), 1);
+/// assert_eq!((SRGB | HDR).iter().count(), 3);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct SpaceSet {
+    bits: [u64; NUM_WORDS],
+}
+
+impl SpaceSet {
+    /// Create a new [`SpaceSet`] containing no spaces.
+    pub const fn empty() -> Self {
+        Self {
+            bits: [0; NUM_WORDS],
+        }
+    }
+
+    /// Create a new [`SpaceSet`] containing every space supported by the library.
+    pub const fn all() -> Self {
+        let mut set = Self::empty();
+        let mut i = 0;
+        while i < Spaces::COUNT {
+            set.bits[i / 64] |= 1 << (i % 64);
+            i += 1;
+        }
+        set
+    }
+
+    /// Create a new [`SpaceSet`] containing the given spaces.
+    pub const fn from_spaces(spaces: &[Spaces]) -> Self {
+        let mut set = Self::empty();
+        let mut i = 0;
+        while i < spaces.len() {
+            set = set.with(spaces[i]);
+            i += 1;
+        }
+        set
+    }
+
+    /// Returns a copy of this set with `space` added to it.
+    pub const fn with(mut self, space: Spaces) -> Self {
+        let index = space.index();
+        self.bits[index / 64] |= 1 << (index % 64);
+        self
+    }
+
+    /// Returns a copy of this set with `space` removed from it.
+    pub const fn without(mut self, space: Spaces) -> Self {
+        let index = space.index();
+        self.bits[index / 64] &= !(1 << (index % 64));
+        self
+    }
+
+    /// Adds `space` to this set, returning whether it was newly inserted.
+    pub fn insert(&mut self, space: Spaces) -> bool {
+        let inserted = !self.contains(space);
+        *self = self.with(space);
+        inserted
+    }
+
+    /// Removes `space` from this set, returning whether it was present.
+    pub fn remove(&mut self, space: Spaces) -> bool {
+        let removed = self.contains(space);
+        *self = self.without(space);
+        removed
+    }
+
+    /// Whether this set contains `space`.
+    pub const fn contains(&self, space: Spaces) -> bool {
+        let index = space.index();
+        self.bits[index / 64] & (1 << (index % 64)) != 0
+    }
+
+    /// The set of spaces contained in either this set or `other`.
+    pub const fn union(mut self, other: Self) -> Self {
+        let mut i = 0;
+        while i < NUM_WORDS {
+            self.bits[i] |= other.bits[i];
+            i += 1;
+        }
+        self
+    }
+
+    /// The set of spaces contained in both this set and `other`.
+    pub const fn intersection(mut self, other: Self) -> Self {
+        let mut i = 0;
+        while i < NUM_WORDS {
+            self.bits[i] &= other.bits[i];
+            i += 1;
+        }
+        self
+    }
+
+    /// The set of spaces contained in this set but not in `other`.
+    pub const fn difference(mut self, other: Self) -> Self {
+        let mut i = 0;
+        while i < NUM_WORDS {
+            self.bits[i] &= !other.bits[i];
+            i += 1;
+        }
+        self
+    }
+
+    /// Whether every space in this set is also contained in `other`.
+    pub const fn is_subset(&self, other: Self) -> bool {
+        self.difference(other).is_empty()
+    }
+
+    /// Whether this set contains no spaces.
+    pub const fn is_empty(&self) -> bool {
+        let mut i = 0;
+        while i < NUM_WORDS {
+            if self.bits[i] != 0 {
+                return false;
+            }
+            i += 1;
+        }
+        true
+    }
+
+    /// The number of spaces in this set.
+    pub const fn len(&self) -> usize {
+        let mut len = 0;
+        let mut i = 0;
+        while i < NUM_WORDS {
+            len += self.bits[i].count_ones() as usize;
+            i += 1;
+        }
+        len
+    }
+
+    /// Iterate over the spaces in this set, in the order of the variants of [`Spaces`].
+    pub fn iter(&self) -> SpaceSetIter {
+        SpaceSetIter {
+            set: *self,
+            index: 0,
+        }
+    }
+}
+
+impl fmt::Debug for SpaceSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl From<Spaces> for SpaceSet {
+    fn from(space: Spaces) -> Self {
+        Self::empty().with(space)
+    }
+}
+
+impl FromIterator<Spaces> for SpaceSet {
+    fn from_iter<I: IntoIterator<Item = Spaces>>(iter: I) -> Self {
+        let mut set = Self::empty();
+        set.extend(iter);
+        set
+    }
+}
+
+impl Extend<Spaces> for SpaceSet {
+    fn extend<I: IntoIterator<Item = Spaces>>(&mut self, iter: I) {
+        for space in iter {
+            self.insert(space);
+        }
+    }
+}
+
+impl IntoIterator for SpaceSet {
+    type Item = Spaces;
+    type IntoIter = SpaceSetIter;
+
+    fn into_iter(self) -> SpaceSetIter {
+        self.iter()
+    }
+}
+
+impl IntoIterator for &SpaceSet {
+    type Item = Spaces;
+    type IntoIter = SpaceSetIter;
+
+    fn into_iter(self) -> SpaceSetIter {
+        self.iter()
+    }
+}
+
+macro_rules! impl_set_op {
+    ($op:ident, $op_fn:ident, $op_assign:ident, $op_assign_fn:ident, $set_fn:ident) => {
+        impl $op for SpaceSet {
+            type Output = SpaceSet;
+
+            fn $op_fn(self, rhs: SpaceSet) -> SpaceSet {
+                self.$set_fn(rhs)
+            }
+        }
+
+        impl $op_assign for SpaceSet {
+            fn $op_assign_fn(&mut self, rhs: SpaceSet) {
+                *self = self.$set_fn(rhs);
+            }
+        }
+    };
+}
+
+impl_set_op!(BitOr, bitor, BitOrAssign, bitor_assign, union);
+impl_set_op!(BitAnd, bitand, BitAndAssign, bitand_assign, intersection);
+impl_set_op!(Sub, sub, SubAssign, sub_assign, difference);
+
+/// The linear, wide-gamut hub spaces preferred by [`negotiate`] when a producer and consumer share
+/// several spaces, from most to least preferred.
+pub const NEGOTIATION_HUBS: [Spaces; 5] = [
+    Spaces::Aces2065,
+    Spaces::AcesCg,
+    Spaces::CieXYZ,
+    Spaces::Bt2020,
+    Spaces::LinearSrgb,
+];
+
+const GENERIC_SPACES: SpaceSet =
+    SpaceSet::from_spaces(&[Spaces::GenericColor1, Spaces::GenericColor3]);
+
+/// Pick the space that colors should be exchanged in between a `producer` which can produce the
+/// given spaces and a `consumer` which can consume the given spaces.
+///
+/// The result is chosen from the spaces contained in both sets, by the following preference order:
+/// 1. If there is exactly one shared space, it is picked.
+/// 2. Otherwise, the first of the [`NEGOTIATION_HUBS`] which is shared is picked, since converting
+///    through a linear wide-gamut space loses the least information.
+/// 3. Otherwise, the first shared space in the order of the variants of [`Spaces`] is picked,
+///    except that the generic spaces (i.e. [`Spaces::GenericColor3`]) are only picked as a last
+///    resort, since they carry no meaning of their own.
+///
+/// Returns `None` if the sets have no space in common. Since the result only depends on the two
+/// sets, producers and consumers calling this independently will converge on the same space.
+///
+/// ```rust
+/// use cint::{negotiate, SpaceSet, Spaces};
+///
+/// let camera = SpaceSet::from_spaces(&[Spaces::EncodedSrgb, Spaces::AcesCg, Spaces::Bt2020]);
+/// let display = SpaceSet::from_spaces(&[Spaces::EncodedSrgb, Spaces::Bt2020]);
+///
+/// assert_eq!(negotiate(camera, display), Some(Spaces::Bt2020));
+/// assert_eq!(negotiate(camera, SpaceSet::empty()), None);
+/// ```
+pub fn negotiate(producer: SpaceSet, consumer: SpaceSet) -> Option<Spaces> {
+    let shared = producer.intersection(consumer);
+    if shared.len() <= 1 {
+        return shared.iter().next();
+    }
+
+    NEGOTIATION_HUBS
+        .iter()
+        .copied()
+        .find(|&hub| shared.contains(hub))
+        .or_else(|| shared.difference(GENERIC_SPACES).iter().next())
+        .or_else(|| shared.iter().next())
+}
+
+/// An iterator over the spaces in a [`SpaceSet`], created by [`SpaceSet::iter`].
+#[derive(Clone, Debug)]
+pub struct SpaceSetIter {
+    set: SpaceSet,
+    index: usize,
+}
+
+impl Iterator for SpaceSetIter {
+    type Item = Spaces;
+
+    fn next(&mut self) -> Option<Spaces> {
+        while let Some(space) = Spaces::from_index(self.index) {
+            self.index += 1;
+            if self.set.contains(space) {
+                return Some(space);
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(Spaces::COUNT - self.index))
+    }
+}
+
+impl core::iter::FusedIterator for SpaceSetIter {}
@@ -0,0 +1,153 @@
+use crate::{Spaces, ALL_SPACES, NUM_SPACES};
+
+impl Spaces {
+    /// The number of variants of [`Spaces`], i.e. one more than the largest [`Spaces::index`].
+    pub const COUNT: usize = NUM_SPACES;
+
+    /// A dense index for this space, in `0..Spaces::COUNT`, which can be used to index arrays and
+    /// build lookup tables (see also [`SpaceMap`][crate::SpaceMap]).
+    ///
+    /// The index is the position of the variant in the declaration order of [`Spaces`]. New spaces
+    /// are only ever added at the end, so the index of a space is stable across releases.
+    pub const fn index(self) -> usize {
+        self as usize
+    }
+
+    /// The space with the given [`Spaces::index`], or `None` if `index >= Spaces::COUNT`.
+    pub fn from_index(index: usize) -> Option<Spaces> {
+        ALL_SPACES.get(index).copied()
+    }
+
+    /// The space that colors in this space are best converted through when exchanging them with
+    /// code that doesn't support this space directly, or `None` if the space has no defined meaning
+    /// (i.e. [`Spaces::GenericColor3`]) or can't be converted to any other space (i.e.
+    /// [`Spaces::ScotopicLuminance`] or [`Spaces::AlphaOpic`]).
+    ///
+    /// This is:
+    /// * [`Spaces::LinearSrgb`] for spaces with the sRGB/Rec.709 primaries and for relative spaces
+    ///   which are defined in terms of an RGB space, like HSL or YCbCr.
+    /// * [`Spaces::Aces2065`] for ACES and camera log spaces, i.e. scene-referred film workflows.
+    /// * [`Spaces::CieXYZ`] for everything else, i.e. wide gamut RGB spaces, RGB spaces with other
+    ///   primaries like BT.601, extended-gamut encodings like xvYCC, and CIE or perceptual spaces.
+    ///
+    /// Spaces which are themselves an exchange space return themselves.
+    pub fn canonical_exchange_space(&self) -> Option<Spaces> {
+        match *self {
+            Self::EncodedSrgb
+            | Self::LinearSrgb
+            | Self::EncodedRec709
+            | Self::Rec709
+            | Self::Luma
+            | Self::Hsl
+            | Self::Hsv
+            | Self::Hsi
+            | Self::YCbCr
+            | Self::YPrimeCbCr
+            | Self::YPbPr
+            | Self::YPrimePbPr
+            | Self::Yuv
+            | Self::YCxCz
+            | Self::Yiq
+            | Self::YDbDr
+            | Self::ScRgb
+            | Self::EncodedExtendedSrgb
+            | Self::EncodedBt1886 => Some(Self::LinearSrgb),
+            Self::AcesCg
+            | Self::Aces2065
+            | Self::AcesCc
+            | Self::AcesCct
+            | Self::ArriLogC3
+            | Self::ArriLogC4
+            | Self::SonySLog3
+            | Self::SonySLog3Cine
+            | Self::PanasonicVLog
+            | Self::RedLog3G10
+            | Self::CanonLog3
+            | Self::BlackmagicFilmGen5
+            | Self::DaVinciIntermediate
+            | Self::FilmLightTLogEGamut
+            | Self::DjiDLog
+            | Self::GoProProtune
+            | Self::CineonLog
+            | Self::FujiFLog
+            | Self::FujiFLog2
+            | Self::NikonNLog => Some(Self::Aces2065),
+            Self::Luminance
+            | Self::DisplayP3
+            | Self::EncodedDisplayP3
+            | Self::DciP3
+            | Self::DciXYZPrime
+            | Self::Bt2020
+            | Self::EncodedBt2020
+            | Self::Bt2100
+            | Self::EncodedBt2100PQ
+            | Self::EncodedBt2100HLG
+            | Self::ICtCpPQ
+            | Self::ICtCpHLG
+            | Self::CieXYZ
+            | Self::CieLab
+            | Self::CieLCh
+            | Self::Oklab
+            | Self::Oklch
+            | Self::ProPhotoRgb
+            | Self::EncodedProPhotoRgb
+            | Self::AdobeRgb1998
+            | Self::EncodedAdobeRgb1998
+            | Self::WideGamutRgb
+            | Self::EncodedWideGamutRgb
+            | Self::EncodedDisplayP3PQ
+            | Self::EncodedDciP3
+            | Self::XvYcc
+            | Self::Bt601_625
+            | Self::EncodedBt601_625
+            | Self::Bt601_525
+            | Self::EncodedBt601_525
+            | Self::GammaRgb => Some(Self::CieXYZ),
+            Self::GenericColor1
+            | Self::GenericColor3
+            | Self::ScotopicLuminance
+            | Self::AlphaOpic => None,
+        }
+    }
+
+    /// Whether the gamut of this space is known to contain the gamut of `other`, i.e. whether colors
+    /// can be handed from `other` to this space without clipping.
+    ///
+    /// This knows about the chain sRGB/Rec.709 ⊂ Display P3 (and DCI-P3) ⊂ BT.2020 ⊂ ACES AP1 ⊂ ACES AP0,
+    /// and that [`Spaces::CieXYZ`] contains every gamut. Returns `None` if the relationship between
+    /// the two spaces is not known.
+    pub fn gamut_contains(&self, other: Spaces) -> Option<bool> {
+        if *self == other || *self == Self::CieXYZ {
+            return Some(true);
+        }
+        match (self.gamut_rank(), other.gamut_rank()) {
+            (Some(rank), Some(other_rank)) => Some(rank >= other_rank),
+            _ => None,
+        }
+    }
+
+    /// The position of this space's gamut in the chain of nested gamuts known to
+    /// [`Spaces::gamut_contains`], if any.
+    fn gamut_rank(&self) -> Option<u8> {
+        match *self {
+            Self::EncodedSrgb
+            | Self::LinearSrgb
+            | Self::EncodedRec709
+            | Self::Rec709
+            | Self::EncodedBt1886 => Some(0),
+            Self::DisplayP3
+            | Self::EncodedDisplayP3
+            | Self::EncodedDisplayP3PQ
+            | Self::DciP3
+            | Self::EncodedDciP3 => Some(1),
+            Self::Bt2020
+            | Self::EncodedBt2020
+            | Self::Bt2100
+            | Self::EncodedBt2100PQ
+            | Self::EncodedBt2100HLG => Some(2),
+            Self::AcesCg | Self::AcesCc | Self::AcesCct => Some(3),
+            Self::Aces2065 => Some(4),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use core::marker::PhantomData;
+
+use crate::ColorType;
+
+/// A color tagged with an application-defined marker type `Tag`, i.e. to distinguish "albedo"
+/// colors from "emission" colors which share the same space.
+///
+/// This has the same layout as `ColorTy` and passes through its [`ColorType`], array, and
+/// `bytemuck` impls.
+#[repr(transparent)]
+pub struct Tagged<ColorTy: ColorType, Tag> {
+    /// The contained color.
+    pub color: ColorTy,
+    _tag: PhantomData<fn() -> Tag>,
+}
+
+impl<ColorTy: ColorType, Tag> Tagged<ColorTy, Tag> {
+    /// Tag `color` with `Tag`.
+    pub const fn new(color: ColorTy) -> Self {
+        Self {
+            color,
+            _tag: PhantomData,
+        }
+    }
+
+    /// Remove the tag, returning the contained color.
+    pub fn into_inner(self) -> ColorTy {
+        self.color
+    }
+
+    /// Replace the tag with `NewTag`.
+    pub fn retag<NewTag>(self) -> Tagged<ColorTy, NewTag> {
+        Tagged::new(self.color)
+    }
+}
+
+impl<ColorTy: ColorType, Tag> From<ColorTy> for Tagged<ColorTy, Tag> {
+    fn from(color: ColorTy) -> Self {
+        Self::new(color)
+    }
+}
+
+impl_transparent_wrapper! {
+    Tagged<ColorTy, Tag> {
+        marker: _tag,
+        debug: "tag" => core::any::type_name::<Tag>(),
+    }
+}
@@ -0,0 +1,194 @@
+//! Mapping between TIFF color signaling and `cint` pixel formats.
+//!
+//! TIFF describes the colors of an image with the `PhotometricInterpretation` tag, refined by the
+//! `SampleFormat`, `BitsPerSample`, `ExtraSamples`, `PlanarConfiguration`, and `YCbCrSubSampling`
+//! tags, and optionally overridden by a `TransferFunction` tag or an embedded ICC profile.
+//! [`TiffColorInfo`] collects the relevant tag values so that [`TiffColorInfo::pixel_format`] can
+//! translate them into a [`PixelFormat`].
+
+use core::convert::TryFrom;
+
+use crate::{
+    AlphaState, ChromaSubsampling, ColorDescriptor, ComponentType, Error, MatrixCoefficients,
+    PixelFormat, PlaneLayout, Spaces,
+};
+
+/// The values of the TIFF `PhotometricInterpretation` tag (262).
+#[repr(u16)]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum PhotometricInterpretation {
+    /// Grayscale, where zero is white.
+    WhiteIsZero = 0,
+    /// Grayscale, where zero is black (aka `MinIsBlack`).
+    BlackIsZero = 1,
+    /// RGB.
+    Rgb = 2,
+    /// Indices into a color map.
+    Palette = 3,
+    /// A transparency mask.
+    TransparencyMask = 4,
+    /// Separated inks, usually CMYK.
+    Separated = 5,
+    /// Y'CbCr.
+    YCbCr = 6,
+    /// CIE L\*a\*b\* with signed a\* and b\* components.
+    CieLab = 8,
+    /// CIE L\*a\*b\* as encoded by ICC profiles, with unsigned a\* and b\* components.
+    IccLab = 9,
+    /// CIE L\*a\*b\* as encoded by ITU-T T.42.
+    ItuLab = 10,
+    /// Log-encoded luminance (SGI LogL).
+    LogL = 32844,
+    /// Log-encoded luminance and chroma (SGI LogLuv).
+    LogLuv = 32845,
+}
+
+impl PhotometricInterpretation {
+    /// The value of the `PhotometricInterpretation` tag for this interpretation.
+    pub const fn tag_value(self) -> u16 {
+        self as u16
+    }
+
+    /// The interpretation for a value of the `PhotometricInterpretation` tag, or `None` if it is
+    /// not a known value.
+    pub const fn from_tag_value(value: u16) -> Option<Self> {
+        match value {
+            0 => Some(Self::WhiteIsZero),
+            1 => Some(Self::BlackIsZero),
+            2 => Some(Self::Rgb),
+            3 => Some(Self::Palette),
+            4 => Some(Self::TransparencyMask),
+            5 => Some(Self::Separated),
+            6 => Some(Self::YCbCr),
+            8 => Some(Self::CieLab),
+            9 => Some(Self::IccLab),
+            10 => Some(Self::ItuLab),
+            32844 => Some(Self::LogL),
+            32845 => Some(Self::LogLuv),
+            _ => None,
+        }
+    }
+}
+
+/// The values of the TIFF `ExtraSamples` tag (338) for the first extra sample of a pixel.
+#[repr(u16)]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum ExtraSample {
+    /// Unspecified data, which is not treated as alpha.
+    Unspecified = 0,
+    /// Associated (premultiplied) alpha.
+    AssociatedAlpha = 1,
+    /// Unassociated (straight) alpha.
+    UnassociatedAlpha = 2,
+}
+
+/// The tag values of a TIFF image which determine how its colors are interpreted.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct TiffColorInfo {
+    /// The value of the `PhotometricInterpretation` tag.
+    pub photometric_interpretation: PhotometricInterpretation,
+    /// The value of the `SampleFormat` tag (339), where 1 is unsigned, 2 is signed, and 3 is float.
+    pub sample_format: u16,
+    /// The value of the `BitsPerSample` tag (258).
+    pub bits_per_sample: u16,
+    /// The first value of the `ExtraSamples` tag, if present.
+    pub extra_sample: Option<ExtraSample>,
+    /// Whether the `PlanarConfiguration` tag (284) is 2, i.e. each component is stored separately.
+    pub planar: bool,
+    /// The value of the `YCbCrSubSampling` tag (530), which only applies to Y'CbCr images.
+    pub ycbcr_subsampling: [u16; 2],
+    /// Whether the image has a `TransferFunction` tag (301).
+    pub has_transfer_function: bool,
+    /// Whether the image has an embedded ICC profile, i.e. an `ICCProfile` tag (34675).
+    pub has_icc_profile: bool,
+}
+
+impl TiffColorInfo {
+    /// Create a new [`TiffColorInfo`] with the default values of the TIFF specification for the
+    /// tags other than `PhotometricInterpretation`, i.e. unsigned 8-bit chunky samples and 2x2 Y'CbCr
+    /// subsampling.
+    pub fn new(photometric_interpretation: PhotometricInterpretation) -> Self {
+        Self {
+            photometric_interpretation,
+            sample_format: 1,
+            bits_per_sample: 8,
+            extra_sample: None,
+            planar: false,
+            ycbcr_subsampling: [2, 2],
+            has_transfer_function: false,
+            has_icc_profile: false,
+        }
+    }
+
+    /// Translate the tag values into a [`PixelFormat`].
+    ///
+    /// * `BlackIsZero` is described as [`Spaces::Luma`], or as [`Spaces::Luminance`] for float samples.
+    /// * `Rgb` is described as [`Spaces::EncodedSrgb`], or as [`Spaces::LinearSrgb`] for float samples.
+    /// * `YCbCr` is described as full-range [`Spaces::YPrimeCbCr`] with the BT.601 matrix, as is the
+    ///   default in TIFF.
+    /// * The Lab interpretations are described as [`Spaces::CieLab`]; note that the encoding of the
+    ///   components still differs between them.
+    ///
+    /// Images with a `TransferFunction` tag or an ICC profile are described with the generic space of
+    /// the same number of components instead, since their colors can only be interpreted through that
+    /// metadata, except for the Lab interpretations, which are absolute.
+    ///
+    /// Fails with [`Error::UnsupportedFormat`] for other interpretations, since `cint` has no
+    /// equivalent for inverted grayscale, palettes, separated inks, or log-encoded data.
+    pub fn pixel_format(&self) -> Result<PixelFormat, Error> {
+        let component_type = match self.sample_format {
+            1 => ComponentType::Unsigned,
+            2 => ComponentType::Signed,
+            3 => ComponentType::Float,
+            _ => return Err(Error::UnsupportedFormat),
+        };
+        let bits_per_component =
+            u8::try_from(self.bits_per_sample).map_err(|_| Error::UnsupportedFormat)?;
+        let is_float = component_type == ComponentType::Float;
+        let has_override = self.has_transfer_function || self.has_icc_profile;
+
+        let space = match self.photometric_interpretation {
+            PhotometricInterpretation::BlackIsZero if has_override => Spaces::GenericColor1,
+            PhotometricInterpretation::BlackIsZero if is_float => Spaces::Luminance,
+            PhotometricInterpretation::BlackIsZero => Spaces::Luma,
+            PhotometricInterpretation::Rgb if has_override => Spaces::GenericColor3,
+            PhotometricInterpretation::Rgb if is_float => Spaces::LinearSrgb,
+            PhotometricInterpretation::Rgb => Spaces::EncodedSrgb,
+            PhotometricInterpretation::YCbCr if has_override => Spaces::GenericColor3,
+            PhotometricInterpretation::YCbCr => Spaces::YPrimeCbCr,
+            PhotometricInterpretation::CieLab
+            | PhotometricInterpretation::IccLab
+            | PhotometricInterpretation::ItuLab => Spaces::CieLab,
+            _ => return Err(Error::UnsupportedFormat),
+        };
+        let alpha_state = match self.extra_sample {
+            Some(ExtraSample::AssociatedAlpha) => AlphaState::Premultiplied,
+            Some(ExtraSample::UnassociatedAlpha) => AlphaState::Straight,
+            Some(ExtraSample::Unspecified) | None => AlphaState::None,
+        };
+
+        let mut format = PixelFormat::new(
+            ColorDescriptor {
+                alpha_state,
+                ..ColorDescriptor::new(space)
+            },
+            component_type,
+            bits_per_component,
+        );
+        if self.planar {
+            format.layout = PlaneLayout::Planar;
+        }
+        if self.photometric_interpretation == PhotometricInterpretation::YCbCr {
+            let [horizontal, vertical] = self.ycbcr_subsampling;
+            format.chroma_subsampling = u8::try_from(horizontal)
+                .ok()
+                .zip(u8::try_from(vertical).ok())
+                .and_then(|(horizontal, vertical)| {
+                    ChromaSubsampling::from_factors(horizontal, vertical)
+                })
+                .ok_or(Error::UnsupportedFormat)?;
+            format.matrix_coefficients = Some(MatrixCoefficients::Bt601);
+        }
+        Ok(format)
+    }
+}
@@ -0,0 +1,258 @@
+use crate::Spaces;
+
+/// A transfer function relating linear light to nonlinear encoded values.
+///
+/// The defining constants of each transfer function are available as data through
+/// [`TransferFunction::parameters`]; `cint` itself does not apply them.
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum TransferFunction {
+    /// No transfer function; values are linear.
+    Linear,
+    /// The sRGB transfer functions defined in IEC 61966-2-1, also used by Display P3.
+    Srgb,
+    /// The BT.709 OETF, also used by BT.601 and BT.2020.
+    Bt709,
+    /// The BT.1886 reference display EOTF, a pure 2.4 gamma.
+    Bt1886,
+    /// A pure 563/256 (approximately 2.2) gamma, as used by Adobe RGB (1998).
+    AdobeRgb1998,
+    /// The ROMM RGB OETF defined in ISO 22028-2, as used by ProPhoto RGB.
+    ProPhoto,
+    /// A pure 2.6 gamma, as used for digital cinema projection.
+    Dci,
+    /// The Perceptual Quantizer (PQ) EOTF defined in SMPTE ST 2084 and BT.2100.
+    Pq,
+    /// The Hybrid Log-Gamma (HLG) OETF defined in BT.2100.
+    Hlg,
+    /// The ACEScc logarithmic encoding defined in ACES S-2014-003.
+    AcesCc,
+    /// The ACEScct logarithmic encoding with a linear toe defined in ACES S-2016-001.
+    AcesCct,
+    /// A pure 2.2 gamma, as assumed by many displays and legacy formats.
+    Gamma22,
+}
+
+/// The constants defining a power function with a linear segment near black, such as the
+/// sRGB or BT.709 transfer functions.
+///
+/// Encoding is `linear_slope * x` for `x < linear_cutoff`, and
+/// `(1 + offset) * x.powf(1 / gamma) - offset` otherwise.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct PiecewiseGammaParameters {
+    /// The exponent of the power segment when decoding.
+    pub gamma: f64,
+    /// The offset applied to the power segment.
+    pub offset: f64,
+    /// The slope of the linear segment.
+    pub linear_slope: f64,
+    /// The linear value below which the linear segment is used.
+    pub linear_cutoff: f64,
+}
+
+/// The constants defining the Perceptual Quantizer (PQ) transfer function, as in SMPTE ST 2084.
+///
+/// Decoding a normalized encoded value `e` is
+/// `peak_luminance * (max(e^(1/m2) - c1, 0) / (c2 - c3 * e^(1/m2)))^(1/m1)` nits.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct PqParameters {
+    /// The m1 constant.
+    pub m1: f64,
+    /// The m2 constant.
+    pub m2: f64,
+    /// The c1 constant.
+    pub c1: f64,
+    /// The c2 constant.
+    pub c2: f64,
+    /// The c3 constant.
+    pub c3: f64,
+    /// The luminance, in nits (cd/m²), represented by an encoded value of 1.0.
+    pub peak_luminance: f64,
+}
+
+/// The constants defining the Hybrid Log-Gamma (HLG) OETF, as in BT.2100.
+///
+/// Encoding a normalized scene linear value `x` is `(3 * x).sqrt()` for `x <= 1 / 12`, and
+/// `a * (12 * x - b).ln() + c` otherwise.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct HlgParameters {
+    /// The a constant.
+    pub a: f64,
+    /// The b constant.
+    pub b: f64,
+    /// The c constant.
+    pub c: f64,
+}
+
+/// The constants defining the ACEScc and ACEScct logarithmic encodings.
+///
+/// The logarithmic segment of both encodes `x` as `(x.log2() + log_offset) / log_scale`. ACEScct
+/// instead encodes `toe_slope * x + toe_offset` for `x <= linear_cutoff`, while ACEScc has no
+/// toe, so its `toe_slope` and `toe_offset` are 0 and `linear_cutoff` is the value below which
+/// it switches to its alternate encoding for very small values.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct AcesLogParameters {
+    /// The offset added to the base 2 logarithm of linear values.
+    pub log_offset: f64,
+    /// The divisor applied to the offset logarithm.
+    pub log_scale: f64,
+    /// The linear value below which the toe segment is used.
+    pub linear_cutoff: f64,
+    /// The encoded value corresponding to `linear_cutoff`.
+    pub encoded_cutoff: f64,
+    /// The slope of the linear toe segment.
+    pub toe_slope: f64,
+    /// The offset of the linear toe segment.
+    pub toe_offset: f64,
+}
+
+/// The defining constants of a [`TransferFunction`].
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub enum TransferFunctionParameters {
+    /// Values are linear, so there are no parameters.
+    Linear,
+    /// A pure power function, with the given exponent when decoding.
+    Gamma(f64),
+    /// A power function with a linear segment near black.
+    PiecewiseGamma(PiecewiseGammaParameters),
+    /// The Perceptual Quantizer (PQ) transfer function.
+    Pq(PqParameters),
+    /// The Hybrid Log-Gamma (HLG) transfer function.
+    Hlg(HlgParameters),
+    /// The ACEScc or ACEScct logarithmic encodings.
+    AcesLog(AcesLogParameters),
+}
+
+/// The constants defining the sRGB transfer functions (IEC 61966-2-1).
+pub const SRGB_PARAMETERS: PiecewiseGammaParameters = PiecewiseGammaParameters {
+    gamma: 2.4,
+    offset: 0.055,
+    linear_slope: 12.92,
+    linear_cutoff: 0.0031308,
+};
+
+/// The constants defining the BT.709 OETF.
+pub const BT709_PARAMETERS: PiecewiseGammaParameters = PiecewiseGammaParameters {
+    gamma: 1.0 / 0.45,
+    offset: 0.099,
+    linear_slope: 4.5,
+    linear_cutoff: 0.018,
+};
+
+/// The constants defining the ROMM RGB OETF (ISO 22028-2).
+pub const PROPHOTO_PARAMETERS: PiecewiseGammaParameters = PiecewiseGammaParameters {
+    gamma: 1.8,
+    offset: 0.0,
+    linear_slope: 16.0,
+    linear_cutoff: 1.0 / 512.0,
+};
+
+/// The exponent of the BT.1886 reference display EOTF.
+pub const BT1886_GAMMA: f64 = 2.4;
+
+/// The exponent of the Adobe RGB (1998) transfer function.
+pub const ADOBE_RGB_1998_GAMMA: f64 = 563.0 / 256.0;
+
+/// The exponent of the digital cinema projection transfer function.
+pub const DCI_GAMMA: f64 = 2.6;
+
+/// The exponent of the pure 2.2 gamma transfer function.
+pub const GAMMA22_GAMMA: f64 = 2.2;
+
+/// The constants defining the Perceptual Quantizer (SMPTE ST 2084).
+pub const PQ_PARAMETERS: PqParameters = PqParameters {
+    m1: 2610.0 / 16384.0,
+    m2: 2523.0 / 4096.0 * 128.0,
+    c1: 3424.0 / 4096.0,
+    c2: 2413.0 / 4096.0 * 32.0,
+    c3: 2392.0 / 4096.0 * 32.0,
+    peak_luminance: 10000.0,
+};
+
+/// The constants defining the Hybrid Log-Gamma OETF (BT.2100).
+pub const HLG_PARAMETERS: HlgParameters = HlgParameters {
+    a: 0.17883277,
+    b: 0.28466892,
+    c: 0.55991073,
+};
+
+/// The constants defining the ACEScc encoding (ACES S-2014-003).
+pub const ACESCC_PARAMETERS: AcesLogParameters = AcesLogParameters {
+    log_offset: 9.72,
+    log_scale: 17.52,
+    linear_cutoff: 1.0 / 32768.0,
+    encoded_cutoff: (-15.0 + 9.72) / 17.52,
+    toe_slope: 0.0,
+    toe_offset: 0.0,
+};
+
+/// The constants defining the ACEScct encoding (ACES S-2016-001).
+pub const ACESCCT_PARAMETERS: AcesLogParameters = AcesLogParameters {
+    log_offset: 9.72,
+    log_scale: 17.52,
+    linear_cutoff: 0.0078125,
+    encoded_cutoff: 0.155251141552511,
+    toe_slope: 10.5402377416545,
+    toe_offset: 0.0729055341958355,
+};
+
+impl TransferFunction {
+    /// The defining constants of this transfer function.
+    pub const fn parameters(self) -> TransferFunctionParameters {
+        match self {
+            Self::Linear => TransferFunctionParameters::Linear,
+            Self::Srgb => TransferFunctionParameters::PiecewiseGamma(SRGB_PARAMETERS),
+            Self::Bt709 => TransferFunctionParameters::PiecewiseGamma(BT709_PARAMETERS),
+            Self::Bt1886 => TransferFunctionParameters::Gamma(BT1886_GAMMA),
+            Self::AdobeRgb1998 => TransferFunctionParameters::Gamma(ADOBE_RGB_1998_GAMMA),
+            Self::ProPhoto => TransferFunctionParameters::PiecewiseGamma(PROPHOTO_PARAMETERS),
+            Self::Dci => TransferFunctionParameters::Gamma(DCI_GAMMA),
+            Self::Pq => TransferFunctionParameters::Pq(PQ_PARAMETERS),
+            Self::Hlg => TransferFunctionParameters::Hlg(HLG_PARAMETERS),
+            Self::AcesCc => TransferFunctionParameters::AcesLog(ACESCC_PARAMETERS),
+            Self::AcesCct => TransferFunctionParameters::AcesLog(ACESCCT_PARAMETERS),
+            Self::Gamma22 => TransferFunctionParameters::Gamma(GAMMA22_GAMMA),
+        }
+    }
+}
+
+impl Spaces {
+    /// The [`TransferFunction`] used by colors in this space, or `None` if the space is not
+    /// an RGB space or uses a transfer function not represented by [`TransferFunction`].
+    pub fn transfer_function(&self) -> Option<TransferFunction> {
+        match *self {
+            Self::LinearSrgb
+            | Self::Rec709
+            | Self::AcesCg
+            | Self::Aces2065
+            | Self::DisplayP3
+            | Self::DciP3
+            | Self::Bt2020
+            | Self::Bt2100
+            | Self::ProPhotoRgb
+            | Self::AdobeRgb1998
+            | Self::WideGamutRgb
+            | Self::ScRgb
+            | Self::Bt601_625
+            | Self::Bt601_525 => Some(TransferFunction::Linear),
+            Self::EncodedSrgb | Self::EncodedDisplayP3 | Self::EncodedExtendedSrgb => {
+                Some(TransferFunction::Srgb)
+            }
+            Self::EncodedRec709
+            | Self::EncodedBt2020
+            | Self::EncodedBt601_625
+            | Self::EncodedBt601_525 => Some(TransferFunction::Bt709),
+            Self::EncodedBt1886 => Some(TransferFunction::Bt1886),
+            Self::EncodedAdobeRgb1998 | Self::EncodedWideGamutRgb => {
+                Some(TransferFunction::AdobeRgb1998)
+            }
+            Self::EncodedProPhotoRgb => Some(TransferFunction::ProPhoto),
+            Self::EncodedDciP3 => Some(TransferFunction::Dci),
+            Self::EncodedBt2100PQ | Self::EncodedDisplayP3PQ => Some(TransferFunction::Pq),
+            Self::EncodedBt2100HLG => Some(TransferFunction::Hlg),
+            Self::AcesCc => Some(TransferFunction::AcesCc),
+            Self::AcesCct => Some(TransferFunction::AcesCct),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,62 @@
+//! Aliases for commonly used concrete color types.
+//!
+//! These are provided so that application code and function signatures read naturally, and so
+//! that crates across the ecosystem can converge on the same names.
+
+use crate::{Alpha, Bgr, EncodedSrgb, LinearSrgb, Oklab, Oklch, PremultipliedAlpha};
+
+/// An 8-bit encoded sRGB color.
+pub type Srgb8 = EncodedSrgb<u8>;
+/// An 8-bit encoded sRGB color with a separate alpha component.
+pub type Srgba8 = Alpha<EncodedSrgb<u8>>;
+/// An 8-bit encoded sRGB color with a premultiplied alpha component.
+pub type PremulSrgba8 = PremultipliedAlpha<EncodedSrgb<u8>>;
+
+/// An 8-bit encoded sRGB color stored in BGR order.
+pub type Bgr8 = Bgr<EncodedSrgb<u8>>;
+/// An 8-bit encoded sRGB color stored in BGR order with a separate alpha component.
+pub type Bgra8 = Alpha<Bgr<EncodedSrgb<u8>>>;
+/// An 8-bit encoded sRGB color stored in BGR order with a premultiplied alpha component.
+pub type PremulBgra8 = PremultipliedAlpha<Bgr<EncodedSrgb<u8>>>;
+
+/// A 16-bit encoded sRGB color.
+pub type Srgb16 = EncodedSrgb<u16>;
+/// A 16-bit encoded sRGB color with a separate alpha component.
+pub type Srgba16 = Alpha<EncodedSrgb<u16>>;
+
+/// A floating point encoded sRGB color.
+pub type SrgbF32 = EncodedSrgb<f32>;
+/// A floating point encoded sRGB color with a separate alpha component.
+pub type SrgbaF32 = Alpha<EncodedSrgb<f32>>;
+
+/// A floating point linear sRGB color.
+pub type LinearSrgbF32 = LinearSrgb<f32>;
+/// A floating point linear sRGB color with a separate alpha component.
+pub type LinearSrgbaF32 = Alpha<LinearSrgb<f32>>;
+/// A floating point linear sRGB color with a premultiplied alpha component.
+pub type PremulLinearSrgbaF32 = PremultipliedAlpha<LinearSrgb<f32>>;
+
+/// A floating point Oklab color.
+pub type Oklab32 = Oklab<f32>;
+/// A floating point Oklab color with a separate alpha component.
+pub type Oklaba32 = Alpha<Oklab<f32>>;
+/// A floating point Oklch color.
+pub type Oklch32 = Oklch<f32>;
+/// A floating point Oklch color with a separate alpha component.
+pub type Oklcha32 = Alpha<Oklch<f32>>;
+
+/// A fixed-point linear sRGB color with 1 integer bit and 15 fractional bits, able to represent
+/// the full 0 to 1 range exactly.
+#[cfg(feature = "fixed")]
+pub type LinearSrgbU1F15 = LinearSrgb<fixed::types::U1F15>;
+/// A fixed-point linear sRGB color with a separate alpha component, with 1 integer bit and 15
+/// fractional bits per component.
+#[cfg(feature = "fixed")]
+pub type LinearSrgbaU1F15 = Alpha<LinearSrgb<fixed::types::U1F15>>;
+/// A fixed-point encoded sRGB color with 1 integer bit and 15 fractional bits, able to represent
+/// the full 0 to 1 range exactly.
+#[cfg(feature = "fixed")]
+pub type SrgbU1F15 = EncodedSrgb<fixed::types::U1F15>;
+/// A fixed-point Oklab color with 2 signed integer bits and 14 fractional bits.
+#[cfg(feature = "fixed")]
+pub type OklabI2F14 = Oklab<fixed::types::I2F14>;
@@ -0,0 +1,415 @@
+//! Mapping between V4L2 (and libcamera) pixel formats and color fields and `cint` pixel formats.
+//!
+//! V4L2 describes the frames delivered by a capture device with a fourcc `pixelformat`, refined by
+//! the `colorspace`, `xfer_func`, `ycbcr_enc`, and `quantization` fields of `struct v4l2_pix_format`.
+//! libcamera uses the same fourcc codes. [`V4l2ColorInfo`] collects these values so that
+//! [`V4l2ColorInfo::pixel_format`] can translate them into a [`PixelFormat`], and
+//! [`V4l2ColorInfo::from_pixel_format`] can translate them back.
+
+use crate::{
+    AlphaState, ChromaSubsampling, ColorDescriptor, ComponentOrder, ComponentType, Error,
+    MatrixCoefficients, PixelFormat, PlaneLayout, SignalRange, SpaceRef, Spaces,
+};
+
+/// Build a fourcc code from its four characters, as the `v4l2_fourcc` macro does.
+pub const fn fourcc(code: &[u8; 4]) -> u32 {
+    (code[0] as u32) | (code[1] as u32) << 8 | (code[2] as u32) << 16 | (code[3] as u32) << 24
+}
+
+/// The values of `enum v4l2_colorspace`.
+pub mod colorspace {
+    pub const DEFAULT: u32 = 0;
+    pub const SMPTE170M: u32 = 1;
+    pub const SMPTE240M: u32 = 2;
+    pub const REC709: u32 = 3;
+    pub const BT878: u32 = 4;
+    pub const SYSTEM_M_470: u32 = 5;
+    pub const SYSTEM_BG_470: u32 = 6;
+    pub const JPEG: u32 = 7;
+    pub const SRGB: u32 = 8;
+    pub const OPRGB: u32 = 9;
+    pub const BT2020: u32 = 10;
+    pub const RAW: u32 = 11;
+    pub const DCI_P3: u32 = 12;
+}
+
+/// The values of `enum v4l2_xfer_func`.
+pub mod xfer_func {
+    pub const DEFAULT: u32 = 0;
+    pub const XFER_709: u32 = 1;
+    pub const SRGB: u32 = 2;
+    pub const OPRGB: u32 = 3;
+    pub const SMPTE240M: u32 = 4;
+    pub const NONE: u32 = 5;
+    pub const DCI_P3: u32 = 6;
+    pub const SMPTE2084: u32 = 7;
+}
+
+/// The values of `enum v4l2_ycbcr_encoding`.
+pub mod ycbcr_enc {
+    pub const DEFAULT: u32 = 0;
+    pub const ENC_601: u32 = 1;
+    pub const ENC_709: u32 = 2;
+    pub const XV601: u32 = 3;
+    pub const XV709: u32 = 4;
+    pub const BT2020: u32 = 6;
+    pub const BT2020_CONST_LUM: u32 = 7;
+    pub const SMPTE240M: u32 = 8;
+}
+
+/// The values of `enum v4l2_quantization`.
+pub mod quantization {
+    pub const DEFAULT: u32 = 0;
+    pub const FULL_RANGE: u32 = 1;
+    pub const LIM_RANGE: u32 = 2;
+}
+
+/// The color filter array pattern of a raw Bayer format, given by the colors of the top-left 2x2
+/// block of the sensor in reading order.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum BayerPattern {
+    Rggb,
+    Bggr,
+    Gbrg,
+    Grbg,
+}
+
+const fn format(
+    space: Spaces,
+    alpha_state: AlphaState,
+    signal_range: SignalRange,
+    bits_per_component: u8,
+    chroma_subsampling: ChromaSubsampling,
+    layout: PlaneLayout,
+    order: ComponentOrder,
+) -> PixelFormat {
+    PixelFormat {
+        descriptor: ColorDescriptor {
+            space: SpaceRef::Builtin(space),
+            alpha_state,
+            signal_range,
+        },
+        component_type: ComponentType::Unsigned,
+        bits_per_component,
+        chroma_subsampling,
+        layout,
+        order,
+        padded: false,
+        matrix_coefficients: None,
+    }
+}
+
+const fn rgb(alpha_state: AlphaState, order: ComponentOrder) -> PixelFormat {
+    format(
+        Spaces::EncodedSrgb,
+        alpha_state,
+        SignalRange::Full,
+        8,
+        ChromaSubsampling::None,
+        PlaneLayout::Packed,
+        order,
+    )
+}
+
+const fn padded(format: PixelFormat) -> PixelFormat {
+    PixelFormat {
+        padded: true,
+        ..format
+    }
+}
+
+const fn yuv(
+    bits: u8,
+    chroma_subsampling: ChromaSubsampling,
+    layout: PlaneLayout,
+    order: ComponentOrder,
+) -> PixelFormat {
+    format(
+        Spaces::YPrimeCbCr,
+        AlphaState::None,
+        SignalRange::Limited,
+        bits,
+        chroma_subsampling,
+        layout,
+        order,
+    )
+}
+
+const fn gray(bits: u8) -> PixelFormat {
+    format(
+        Spaces::Luma,
+        AlphaState::None,
+        SignalRange::Full,
+        bits,
+        ChromaSubsampling::None,
+        PlaneLayout::Packed,
+        ComponentOrder::Native,
+    )
+}
+
+/// The known fourcc codes, in order of preference for mapping a [`PixelFormat`] back to a code.
+const FORMATS: &[(u32, PixelFormat)] = {
+    use AlphaState::{None as Opaque, Straight};
+    use ChromaSubsampling::{Yuv420, Yuv422};
+    use ComponentOrder::*;
+    use PlaneLayout::*;
+
+    &[
+        (fourcc(b"YUYV"), yuv(8, Yuv422, Packed, Native)),
+        (fourcc(b"YVYU"), yuv(8, Yuv422, Packed, Reversed)),
+        (fourcc(b"UYVY"), yuv(8, Yuv422, Packed, ChromaFirst)),
+        (fourcc(b"NV12"), yuv(8, Yuv420, SemiPlanar, Native)),
+        (fourcc(b"NV21"), yuv(8, Yuv420, SemiPlanar, Reversed)),
+        (fourcc(b"NV16"), yuv(8, Yuv422, SemiPlanar, Native)),
+        (fourcc(b"NV61"), yuv(8, Yuv422, SemiPlanar, Reversed)),
+        (
+            fourcc(b"NV24"),
+            yuv(8, ChromaSubsampling::None, SemiPlanar, Native),
+        ),
+        (
+            fourcc(b"NV42"),
+            yuv(8, ChromaSubsampling::None, SemiPlanar, Reversed),
+        ),
+        (fourcc(b"P010"), yuv(10, Yuv420, SemiPlanar, Native)),
+        (fourcc(b"YU12"), yuv(8, Yuv420, Planar, Native)),
+        (fourcc(b"YV12"), yuv(8, Yuv420, Planar, Reversed)),
+        (fourcc(b"422P"), yuv(8, Yuv422, Planar, Native)),
+        (fourcc(b"GREY"), gray(8)),
+        (fourcc(b"Y10 "), gray(10)),
+        (fourcc(b"Y12 "), gray(12)),
+        (fourcc(b"Y16 "), gray(16)),
+        (fourcc(b"RGB3"), rgb(Opaque, Native)),
+        (fourcc(b"BGR3"), rgb(Opaque, Reversed)),
+        (fourcc(b"AB24"), rgb(Straight, Native)),
+        (fourcc(b"AR24"), rgb(Straight, Reversed)),
+        (fourcc(b"BA24"), rgb(Straight, AlphaFirst)),
+        (fourcc(b"RA24"), rgb(Straight, ReversedAlphaFirst)),
+        (fourcc(b"XB24"), padded(rgb(Opaque, Native))),
+        (fourcc(b"XR24"), padded(rgb(Opaque, Reversed))),
+    ]
+};
+
+/// The known raw Bayer fourcc codes, with their pattern and bit depth.
+const BAYER_FORMATS: &[(u32, BayerPattern, u8)] = &[
+    (fourcc(b"RGGB"), BayerPattern::Rggb, 8),
+    (fourcc(b"BA81"), BayerPattern::Bggr, 8),
+    (fourcc(b"GBRG"), BayerPattern::Gbrg, 8),
+    (fourcc(b"GRBG"), BayerPattern::Grbg, 8),
+    (fourcc(b"RG10"), BayerPattern::Rggb, 10),
+    (fourcc(b"BG10"), BayerPattern::Bggr, 10),
+    (fourcc(b"GB10"), BayerPattern::Gbrg, 10),
+    (fourcc(b"BA10"), BayerPattern::Grbg, 10),
+    (fourcc(b"RG12"), BayerPattern::Rggb, 12),
+    (fourcc(b"BG12"), BayerPattern::Bggr, 12),
+    (fourcc(b"GB12"), BayerPattern::Gbrg, 12),
+    (fourcc(b"BA12"), BayerPattern::Grbg, 12),
+    (fourcc(b"RG16"), BayerPattern::Rggb, 16),
+    (fourcc(b"BYR2"), BayerPattern::Bggr, 16),
+    (fourcc(b"GB16"), BayerPattern::Gbrg, 16),
+    (fourcc(b"GR16"), BayerPattern::Grbg, 16),
+];
+
+/// The RGB spaces described by pairs of `colorspace` and (resolved) `xfer_func` values.
+const RGB_SPACES: &[(u32, u32, Spaces)] = &[
+    (colorspace::SRGB, xfer_func::SRGB, Spaces::EncodedSrgb),
+    (colorspace::SRGB, xfer_func::NONE, Spaces::LinearSrgb),
+    (
+        colorspace::REC709,
+        xfer_func::XFER_709,
+        Spaces::EncodedRec709,
+    ),
+    (colorspace::REC709, xfer_func::NONE, Spaces::Rec709),
+    (
+        colorspace::SMPTE170M,
+        xfer_func::XFER_709,
+        Spaces::EncodedBt601_525,
+    ),
+    (colorspace::SMPTE170M, xfer_func::NONE, Spaces::Bt601_525),
+    (
+        colorspace::SYSTEM_BG_470,
+        xfer_func::XFER_709,
+        Spaces::EncodedBt601_625,
+    ),
+    (
+        colorspace::SYSTEM_BG_470,
+        xfer_func::NONE,
+        Spaces::Bt601_625,
+    ),
+    (
+        colorspace::OPRGB,
+        xfer_func::OPRGB,
+        Spaces::EncodedAdobeRgb1998,
+    ),
+    (colorspace::OPRGB, xfer_func::NONE, Spaces::AdobeRgb1998),
+    (
+        colorspace::BT2020,
+        xfer_func::XFER_709,
+        Spaces::EncodedBt2020,
+    ),
+    (
+        colorspace::BT2020,
+        xfer_func::SMPTE2084,
+        Spaces::EncodedBt2100PQ,
+    ),
+    (colorspace::BT2020, xfer_func::NONE, Spaces::Bt2020),
+];
+
+/// The pixel format and color fields of a V4L2 `struct v4l2_pix_format`.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct V4l2ColorInfo {
+    /// The fourcc code of the pixel format, i.e. `fourcc(b"YUYV")`.
+    pub pixelformat: u32,
+    /// One of the [`colorspace`] values.
+    pub colorspace: u32,
+    /// One of the [`xfer_func`] values.
+    pub xfer_func: u32,
+    /// One of the [`ycbcr_enc`] values.
+    pub ycbcr_enc: u32,
+    /// One of the [`quantization`] values.
+    pub quantization: u32,
+}
+
+impl V4l2ColorInfo {
+    /// Create a new [`V4l2ColorInfo`] for the `pixelformat`, with default color fields.
+    pub fn new(pixelformat: u32) -> Self {
+        Self {
+            pixelformat,
+            colorspace: colorspace::DEFAULT,
+            xfer_func: xfer_func::DEFAULT,
+            ycbcr_enc: ycbcr_enc::DEFAULT,
+            quantization: quantization::DEFAULT,
+        }
+    }
+
+    /// The Bayer pattern of the pixel format, if it is a raw Bayer format.
+    pub fn bayer_pattern(&self) -> Option<BayerPattern> {
+        BAYER_FORMATS
+            .iter()
+            .find(|(code, _, _)| *code == self.pixelformat)
+            .map(|(_, pattern, _)| *pattern)
+    }
+
+    /// Translate the fields into a [`PixelFormat`].
+    ///
+    /// Default color fields are resolved as the V4L2 specification describes, i.e. the default
+    /// transfer function and Y'CbCr encoding are derived from the colorspace, and Y'CbCr formats are
+    /// limited range unless the colorspace is `JPEG`. RGB formats are described with the space given
+    /// by the colorspace and transfer function, falling back to [`Spaces::EncodedSrgb`]. Y'CbCr
+    /// formats are described as [`Spaces::YPrimeCbCr`] with the matrix given by the Y'CbCr encoding.
+    ///
+    /// Raw Bayer formats are described as single-component [`Spaces::GenericColor1`] formats, since
+    /// their samples have no color space until they are demosaiced; see
+    /// [`V4l2ColorInfo::bayer_pattern`] for their pattern.
+    ///
+    /// Fails with [`Error::UnsupportedFormat`] if the fourcc code is not known.
+    pub fn pixel_format(&self) -> Result<PixelFormat, Error> {
+        if let Some(&(_, _, bits)) = BAYER_FORMATS
+            .iter()
+            .find(|(code, _, _)| *code == self.pixelformat)
+        {
+            return Ok(PixelFormat::new(
+                ColorDescriptor::new(Spaces::GenericColor1),
+                ComponentType::Unsigned,
+                bits,
+            ));
+        }
+        let (_, mut format) = *FORMATS
+            .iter()
+            .find(|(code, _)| *code == self.pixelformat)
+            .ok_or(Error::UnsupportedFormat)?;
+
+        let is_ycbcr = format.descriptor.space == SpaceRef::Builtin(Spaces::YPrimeCbCr);
+        let colorspace = match self.colorspace {
+            colorspace::DEFAULT if is_ycbcr => colorspace::SMPTE170M,
+            colorspace::DEFAULT => colorspace::SRGB,
+            colorspace => colorspace,
+        };
+
+        if is_ycbcr {
+            let ycbcr_enc = match self.ycbcr_enc {
+                ycbcr_enc::DEFAULT => match colorspace {
+                    colorspace::REC709 | colorspace::DCI_P3 => ycbcr_enc::ENC_709,
+                    colorspace::BT2020 => ycbcr_enc::BT2020,
+                    colorspace::SMPTE240M => ycbcr_enc::SMPTE240M,
+                    _ => ycbcr_enc::ENC_601,
+                },
+                ycbcr_enc => ycbcr_enc,
+            };
+            format.matrix_coefficients = match ycbcr_enc {
+                ycbcr_enc::ENC_601 | ycbcr_enc::XV601 => Some(MatrixCoefficients::Bt601),
+                ycbcr_enc::ENC_709 | ycbcr_enc::XV709 => Some(MatrixCoefficients::Bt709),
+                ycbcr_enc::BT2020 => Some(MatrixCoefficients::Bt2020Ncl),
+                ycbcr_enc::BT2020_CONST_LUM => Some(MatrixCoefficients::Bt2020Cl),
+                _ => None,
+            };
+        } else if format.descriptor.space == SpaceRef::Builtin(Spaces::EncodedSrgb) {
+            let xfer_func = match self.xfer_func {
+                xfer_func::DEFAULT => match colorspace {
+                    colorspace::SRGB | colorspace::JPEG => xfer_func::SRGB,
+                    colorspace::OPRGB => xfer_func::OPRGB,
+                    colorspace::DCI_P3 => xfer_func::DCI_P3,
+                    colorspace::SMPTE240M => xfer_func::SMPTE240M,
+                    colorspace::RAW => xfer_func::NONE,
+                    _ => xfer_func::XFER_709,
+                },
+                xfer_func => xfer_func,
+            };
+            let colorspace = match colorspace {
+                colorspace::JPEG => colorspace::SRGB,
+                colorspace => colorspace,
+            };
+            if let Some(&(_, _, space)) =
+                RGB_SPACES
+                    .iter()
+                    .find(|(known_colorspace, known_xfer_func, _)| {
+                        *known_colorspace == colorspace && *known_xfer_func == xfer_func
+                    })
+            {
+                format.descriptor.space = space.into();
+            }
+        }
+
+        format.descriptor.signal_range = match self.quantization {
+            quantization::FULL_RANGE => SignalRange::Full,
+            quantization::LIM_RANGE => SignalRange::Limited,
+            _ if is_ycbcr && colorspace == colorspace::JPEG => SignalRange::Full,
+            _ => format.descriptor.signal_range,
+        };
+        Ok(format)
+    }
+
+    /// Translate a [`PixelFormat`] into a fourcc code and color fields, the inverse of
+    /// [`V4l2ColorInfo::pixel_format`] for formats other than raw Bayer formats. Color fields which
+    /// can't be described are left at their default values.
+    ///
+    /// Fails with [`Error::UnsupportedFormat`] if the structure of the pixels doesn't match any
+    /// known fourcc code.
+    pub fn from_pixel_format(format: &PixelFormat) -> Result<Self, Error> {
+        let (pixelformat, _) = FORMATS
+            .iter()
+            .find(|(_, known)| known.same_memory_layout(format))
+            .ok_or(Error::UnsupportedFormat)?;
+
+        let mut info = V4l2ColorInfo::new(*pixelformat);
+        if let SpaceRef::Builtin(space) = format.descriptor.space {
+            if let Some(&(colorspace, xfer_func, _)) =
+                RGB_SPACES.iter().find(|(_, _, known)| *known == space)
+            {
+                info.colorspace = colorspace;
+                info.xfer_func = xfer_func;
+            }
+        }
+        info.ycbcr_enc = match format.matrix_coefficients {
+            Some(MatrixCoefficients::Bt601) => ycbcr_enc::ENC_601,
+            Some(MatrixCoefficients::Bt709) => ycbcr_enc::ENC_709,
+            Some(MatrixCoefficients::Bt2020Ncl) => ycbcr_enc::BT2020,
+            Some(MatrixCoefficients::Bt2020Cl) => ycbcr_enc::BT2020_CONST_LUM,
+            _ => ycbcr_enc::DEFAULT,
+        };
+        info.quantization = match format.descriptor.signal_range {
+            SignalRange::Full => quantization::FULL_RANGE,
+            SignalRange::Limited => quantization::LIM_RANGE,
+        };
+        Ok(info)
+    }
+}
@@ -0,0 +1,165 @@
+//! Mapping between the web platform's predefined color spaces and WebGPU canvas configuration and
+//! `cint` pixel formats.
+//!
+//! The web describes the color space of a canvas with a `PredefinedColorSpace` string, `"srgb"` or
+//! `"display-p3"`, which is mapped by [`PredefinedColorSpace`]. A WebGPU canvas is additionally
+//! configured with a texture `format`, a `toneMapping` mode, and an `alphaMode`, which
+//! [`CanvasConfiguration`] collects so that [`CanvasConfiguration::pixel_format`] can translate them
+//! into a [`PixelFormat`], and [`CanvasConfiguration::from_pixel_format`] can translate them back.
+
+use crate::{
+    AlphaState, ColorDescriptor, ComponentOrder, ComponentType, Error, PixelFormat, PlaneLayout,
+    SpaceRef, Spaces,
+};
+
+/// The web platform's `PredefinedColorSpace`, as used by 2D and WebGPU canvases and `ImageData`.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum PredefinedColorSpace {
+    /// `"srgb"`, i.e. [`Spaces::EncodedSrgb`].
+    Srgb,
+    /// `"display-p3"`, i.e. [`Spaces::EncodedDisplayP3`].
+    DisplayP3,
+}
+
+impl PredefinedColorSpace {
+    /// The string value of this color space, i.e. `"display-p3"`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Srgb => "srgb",
+            Self::DisplayP3 => "display-p3",
+        }
+    }
+
+    /// The color space with the given string value, if known.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "srgb" => Some(Self::Srgb),
+            "display-p3" => Some(Self::DisplayP3),
+            _ => None,
+        }
+    }
+
+    /// The `cint` space of this color space.
+    pub fn space(self) -> Spaces {
+        match self {
+            Self::Srgb => Spaces::EncodedSrgb,
+            Self::DisplayP3 => Spaces::EncodedDisplayP3,
+        }
+    }
+
+    /// The color space for a `cint` space, if the web has one. [`Spaces::EncodedExtendedSrgb`] is
+    /// mapped to `"srgb"`, since extended values are allowed in floating point canvases.
+    pub fn from_space(space: Spaces) -> Option<Self> {
+        match space {
+            Spaces::EncodedSrgb | Spaces::EncodedExtendedSrgb => Some(Self::Srgb),
+            Spaces::EncodedDisplayP3 => Some(Self::DisplayP3),
+            _ => None,
+        }
+    }
+}
+
+/// The color related members of a WebGPU `GPUCanvasConfiguration`.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct CanvasConfiguration<'a> {
+    /// The `GPUTextureFormat` of the canvas, i.e. `"bgra8unorm"`.
+    pub format: &'a str,
+    /// The `PredefinedColorSpace` of the canvas, i.e. `"display-p3"`.
+    pub color_space: &'a str,
+    /// The `mode` of the `GPUCanvasToneMapping`, `"standard"` or `"extended"`.
+    pub tone_mapping_mode: &'a str,
+    /// The `GPUCanvasAlphaMode`, `"opaque"` or `"premultiplied"`.
+    pub alpha_mode: &'a str,
+}
+
+impl<'a> CanvasConfiguration<'a> {
+    /// Create a new [`CanvasConfiguration`] for the texture `format`, with the defaults WebGPU uses
+    /// for the other members.
+    pub fn new(format: &'a str) -> Self {
+        Self {
+            format,
+            color_space: "srgb",
+            tone_mapping_mode: "standard",
+            alpha_mode: "opaque",
+        }
+    }
+
+    /// Translate the configuration into a [`PixelFormat`] describing the canvas texture.
+    ///
+    /// An `"opaque"` canvas still has an alpha component in memory, which the browser ignores, so
+    /// it is described as [`AlphaState::Straight`]. A `"rgba16float"` canvas with `"extended"` tone
+    /// mapping in `"srgb"` is described as [`Spaces::EncodedExtendedSrgb`].
+    ///
+    /// Fails with [`Error::UnsupportedFormat`] if the format, color space, or alpha mode is not
+    /// known.
+    pub fn pixel_format(&self) -> Result<PixelFormat, Error> {
+        let (component_type, bits, order) = match self.format {
+            "bgra8unorm" => (ComponentType::Unsigned, 8, ComponentOrder::Reversed),
+            "rgba8unorm" => (ComponentType::Unsigned, 8, ComponentOrder::Native),
+            "rgba16float" => (ComponentType::Float, 16, ComponentOrder::Native),
+            _ => return Err(Error::UnsupportedFormat),
+        };
+        let mut space = PredefinedColorSpace::from_name(self.color_space)
+            .ok_or(Error::UnsupportedFormat)?
+            .space();
+        if component_type == ComponentType::Float
+            && self.tone_mapping_mode == "extended"
+            && space == Spaces::EncodedSrgb
+        {
+            space = Spaces::EncodedExtendedSrgb;
+        }
+        let alpha_state = match self.alpha_mode {
+            "opaque" => AlphaState::Straight,
+            "premultiplied" => AlphaState::Premultiplied,
+            _ => return Err(Error::UnsupportedFormat),
+        };
+
+        let mut descriptor = ColorDescriptor::new(space);
+        descriptor.alpha_state = alpha_state;
+        let mut format = PixelFormat::new(descriptor, component_type, bits);
+        format.order = order;
+        Ok(format)
+    }
+}
+
+impl CanvasConfiguration<'static> {
+    /// Translate a [`PixelFormat`] into a canvas configuration, the inverse of
+    /// [`CanvasConfiguration::pixel_format`]. Spaces with values outside of `[0, 1]`, like
+    /// [`Spaces::EncodedExtendedSrgb`], use `"extended"` tone mapping.
+    ///
+    /// Fails with [`Error::UnsupportedFormat`] if the format can't be used for a canvas, which
+    /// always has an alpha component.
+    pub fn from_pixel_format(format: &PixelFormat) -> Result<Self, Error> {
+        let space = match format.descriptor.space {
+            SpaceRef::Builtin(space) => space,
+            SpaceRef::Custom(_) => return Err(Error::UnsupportedFormat),
+        };
+        let color_space =
+            PredefinedColorSpace::from_space(space).ok_or(Error::UnsupportedFormat)?;
+        let alpha_mode = match format.descriptor.alpha_state {
+            AlphaState::Straight => "opaque",
+            AlphaState::Premultiplied => "premultiplied",
+            AlphaState::None => return Err(Error::UnsupportedFormat),
+        };
+        if format.layout != PlaneLayout::Packed {
+            return Err(Error::UnsupportedFormat);
+        }
+        let texture_format = match (
+            format.component_type,
+            format.bits_per_component,
+            format.order,
+        ) {
+            (ComponentType::Unsigned, 8, ComponentOrder::Reversed) => "bgra8unorm",
+            (ComponentType::Unsigned, 8, ComponentOrder::Native) => "rgba8unorm",
+            (ComponentType::Float, 16, ComponentOrder::Native) => "rgba16float",
+            _ => return Err(Error::UnsupportedFormat),
+        };
+
+        let mut configuration = CanvasConfiguration::new(texture_format);
+        configuration.color_space = color_space.name();
+        configuration.alpha_mode = alpha_mode;
+        if space == Spaces::EncodedExtendedSrgb {
+            configuration.tone_mapping_mode = "extended";
+        }
+        Ok(configuration)
+    }
+}
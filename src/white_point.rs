@@ -0,0 +1,83 @@
+use crate::{Chromaticity, ColorInterop, ColorType, Spaces};
+
+/// A reference white point, i.e. the white that color data has been chromatically adapted to.
+///
+/// Chromaticities are given in the CIE 1931 2° standard observer.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum WhitePoint {
+    /// CIE standard illuminant A, a tungsten-filament light.
+    A,
+    /// CIE standard illuminant C, average daylight (obsolete; used by NTSC 1953).
+    C,
+    /// CIE standard illuminant D50, horizon light. Used by ICC profile connection spaces and ProPhoto RGB.
+    D50,
+    /// CIE standard illuminant D55, mid-morning/mid-afternoon daylight.
+    D55,
+    /// The D60-like white point used by ACES (x = 0.32168, y = 0.33767).
+    D60,
+    /// CIE standard illuminant D65, noon daylight. Used by sRGB, Rec. 709, Rec. 2020, and Display P3.
+    D65,
+    /// CIE standard illuminant D75, north sky daylight.
+    D75,
+    /// The equal-energy illuminant E.
+    E,
+    /// The DCI-P3 theatrical projection white point (x = 0.314, y = 0.351).
+    Dci,
+    /// A white point that is not one of the above.
+    Custom(Chromaticity),
+}
+
+impl WhitePoint {
+    /// The CIE 1931 xy chromaticity of this white point.
+    pub const fn chromaticity(&self) -> Chromaticity {
+        match *self {
+            Self::A => Chromaticity::new(0.44757, 0.40745),
+            Self::C => Chromaticity::new(0.31006, 0.31616),
+            Self::D50 => Chromaticity::new(0.3457, 0.3585),
+            Self::D55 => Chromaticity::new(0.3324, 0.3474),
+            Self::D60 => Chromaticity::new(0.32168, 0.33767),
+            Self::D65 => Chromaticity::new(0.3127, 0.3290),
+            Self::D75 => Chromaticity::new(0.2990, 0.3149),
+            Self::E => Chromaticity::new(1.0 / 3.0, 1.0 / 3.0),
+            Self::Dci => Chromaticity::new(0.314, 0.351),
+            Self::Custom(chromaticity) => chromaticity,
+        }
+    }
+}
+
+impl From<Chromaticity> for WhitePoint {
+    fn from(chromaticity: Chromaticity) -> Self {
+        Self::Custom(chromaticity)
+    }
+}
+
+/// A color tagged with the [`WhitePoint`] it has been chromatically adapted to.
+///
+/// This is mostly useful for spaces that don't imply a white point themselves, such as
+/// [`CieXYZ`][crate::CieXYZ] or [`CieLab`][crate::CieLab], which are commonly used with
+/// both D50 and D65 adaptation.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(C)]
+pub struct WhitePointTagged<ColorTy: ColorType> {
+    /// The contained color.
+    pub color: ColorTy,
+    /// The white point the contained color is adapted to.
+    pub white_point: WhitePoint,
+}
+
+impl<ColorTy: ColorType> WhitePointTagged<ColorTy> {
+    /// Tag `color` as being adapted to `white_point`.
+    pub fn new(color: ColorTy, white_point: WhitePoint) -> Self {
+        Self { color, white_point }
+    }
+}
+
+impl<BaseColorTy: ColorType> ColorType for WhitePointTagged<BaseColorTy> {
+    type ComponentTy = BaseColorTy::ComponentTy;
+    const SPACE: Spaces = BaseColorTy::SPACE;
+    const NUM_COMPONENTS: usize = BaseColorTy::NUM_COMPONENTS;
+}
+
+impl<ColorTy: ColorType> ColorInterop for WhitePointTagged<ColorTy> {
+    type CintTy = Self;
+}